@@ -0,0 +1,186 @@
+use num::Float;
+use std::iter::Sum;
+use tensor::{dot, Tensor};
+use super::{Op, Var};
+
+/// Gradients of one output with respect to every node of the graph
+#[derive(Debug, Clone)]
+pub struct Gradients<T> where T: Float {
+    grads: Vec<Option<Tensor<T>>>
+}
+
+impl<T> Gradients<T> where T: Float + Sum + Send + Sync {
+    /// Gradient with the shape of `var`, zero if the output does not depend on it
+    pub fn wrt(&self, var: Var<T>) -> Tensor<T> {
+        match &self.grads[var.index] {
+            Some(grad) => grad.clone(),
+            None => Tensor::zeros(var.value().shape)
+        }
+    }
+}
+
+impl<'g, T> Var<'g, T> where T: Float + Sum + Send + Sync {
+    /// Reverse pass from this node, seeded with ones
+    pub fn backward(&self) -> Gradients<T> {
+        let nodes = self.graph.nodes.borrow();
+        let mut grads: Vec<Option<Tensor<T>>> = vec![None; nodes.len()];
+        grads[self.index] = Some(Tensor::ones(nodes[self.index].value.shape.clone()));
+
+        for index in (0..=self.index).rev() {
+            let Some(grad) = grads[index].take() else {
+                continue;
+            };
+            let value = &nodes[index].value;
+            let input = |i: usize| &nodes[i].value;
+            match nodes[index].op {
+                Op::Leaf => {},
+                Op::Add(a, b) => {
+                    accumulate(&mut grads, a, unbroadcast(grad.clone(), &input(a).shape));
+                    accumulate(&mut grads, b, unbroadcast(grad.clone(), &input(b).shape));
+                },
+                Op::Sub(a, b) => {
+                    accumulate(&mut grads, a, unbroadcast(grad.clone(), &input(a).shape));
+                    accumulate(&mut grads, b, unbroadcast(map(&grad, |x| -x), &input(b).shape));
+                },
+                Op::Mul(a, b) => {
+                    accumulate(&mut grads, a, unbroadcast(&grad * input(b), &input(a).shape));
+                    accumulate(&mut grads, b, unbroadcast(&grad * input(a), &input(b).shape));
+                },
+                Op::Neg(a) => accumulate(&mut grads, a, map(&grad, |x| -x)),
+                Op::Dot(a, b) => {
+                    if input(a).is_scalar() || input(b).is_scalar() {
+                        accumulate(&mut grads, a, unbroadcast(&grad * input(b), &input(a).shape));
+                        accumulate(&mut grads, b, unbroadcast(&grad * input(a), &input(b).shape));
+                    } else {
+                        accumulate(&mut grads, a, dot(&grad, &input(b).tr()));
+                        accumulate(&mut grads, b, dot(&input(a).tr(), &grad));
+                    }
+                },
+                Op::Exp(a) => accumulate(&mut grads, a, &grad * value),
+                Op::Ln(a) => accumulate(&mut grads, a, &grad / input(a)),
+                Op::Tanh(a) => {
+                    let derivative = map(value, |y| T::one() - y * y);
+                    accumulate(&mut grads, a, &grad * &derivative)
+                },
+                Op::Sigmoid(a) => {
+                    let derivative = map(value, |y| y * (T::one() - y));
+                    accumulate(&mut grads, a, &grad * &derivative)
+                },
+                Op::Powi(a, n) => {
+                    let derivative = map(input(a), |x| T::from(n).unwrap() * x.powi(n - 1));
+                    accumulate(&mut grads, a, &grad * &derivative)
+                },
+                Op::Sum(a) => {
+                    accumulate(&mut grads, a, Tensor::new(input(a).shape.clone(), grad.data[0]))
+                },
+                Op::Mean(a) => {
+                    let count = T::from(input(a).data.len()).unwrap();
+                    accumulate(&mut grads, a, Tensor::new(input(a).shape.clone(), grad.data[0] / count))
+                }
+            }
+            grads[index] = Some(grad);
+        }
+        Gradients { grads }
+    }
+}
+
+fn map<T>(tensor: &Tensor<T>, f: impl Fn(T) -> T) -> Tensor<T> where T: Float {
+    let mut result = tensor.clone();
+    result.apply(f);
+    result
+}
+
+fn accumulate<T>(grads: &mut [Option<Tensor<T>>], index: usize, grad: Tensor<T>) where T: Float {
+    grads[index] = Some(match grads[index].take() {
+        Some(sum) => sum + grad,
+        None => grad
+    });
+}
+
+/// Sums a broadcast gradient back to the shape of the operand
+fn unbroadcast<T>(grad: Tensor<T>, shape: &[usize]) -> Tensor<T> where T: Float {
+    if grad.shape == shape {
+        return grad;
+    }
+    if shape.iter().all(|&dim| dim == 1) {
+        let sum = grad.data.iter().fold(T::zero(), |sum, &x| sum + x);
+        return Tensor { shape: shape.to_vec(), data: vec![sum] };
+    }
+    let mut grad = grad;
+    while grad.shape.len() > shape.len() {
+        grad = grad.sum_axis(0, false);
+    }
+    for (axis, &dim) in shape.iter().enumerate() {
+        if dim == 1 && grad.shape[axis] != 1 {
+            grad = grad.sum_axis(axis, true);
+        }
+    }
+    grad
+}
+
+#[cfg(test)]
+mod tests {
+    use tensor::{dot, Matrix, Scalar, Tensor, Vector, assert_near};
+    use super::super::Graph;
+
+    #[test]
+    fn tanh_at_zero() {
+        let graph = Graph::new();
+        let w = graph.var(Scalar::new(0.0));
+        let grads = w.tanh().backward();
+        assert_eq!(grads.wrt(w), Scalar::new(1.0));
+    }
+
+    #[test]
+    fn product_rule() {
+        let graph = Graph::new();
+        let x = graph.var(Scalar::new(3.0));
+        let y = graph.var(Scalar::new(2.0));
+        let z = (x * y + x.powi(2)).ln();
+        let grads = z.backward();
+        assert_near!(grads.wrt(x), Scalar::new((2.0 + 6.0) / 15.0), 1e-12);
+        assert_near!(grads.wrt(y), Scalar::new(3.0 / 15.0), 1e-12);
+    }
+
+    #[test]
+    fn least_squares_gradient() {
+        let graph = Graph::new();
+        let x = graph.constant(Matrix::new(vec![vec![1.0, 2.0], vec![3.0, 4.0], vec![5.0, 6.0]]));
+        let y = graph.constant(Vector::ket(vec![1.0, 0.0, 2.0]));
+        let w = graph.var(Vector::ket(vec![0.5, -1.0]));
+        let b = graph.var(Scalar::new(0.25));
+        let loss = (x.dot(w) + b - y).powi(2).mean();
+        let grads = loss.backward();
+
+        let residual = dot(&x.value(), &w.value()) + 0.25 - y.value();
+        let expected_w = dot(&x.value().tr(), &residual) * (2.0 / 3.0);
+        assert_eq!(grads.wrt(w).shape, vec![2, 1]);
+        assert_near!(grads.wrt(w), expected_w, 1e-12);
+        assert_near!(grads.wrt(b), Scalar::new(2.0 * residual.data.iter().sum::<f64>() / 3.0), 1e-12);
+    }
+
+    #[test]
+    fn sigmoid_exp_and_broadcast() {
+        let graph = Graph::new();
+        let x = graph.var(Vector::bra(vec![0.0, 1.0]));
+        let bias = graph.var(Vector::ket(vec![1.0, -1.0]));
+        let grads = (x + bias).sigmoid().sum().backward();
+        let sigmoid = |v: f64| 1.0 / (1.0 + (-v).exp());
+        let derivative = |v: f64| sigmoid(v) * (1.0 - sigmoid(v));
+        let expected_x = Vector::bra(vec![derivative(1.0) + derivative(-1.0), derivative(2.0) + derivative(0.0)]);
+        assert_near!(grads.wrt(x), expected_x, 1e-12);
+        assert_eq!(grads.wrt(bias).shape, vec![2, 1]);
+
+        let grads = x.exp().sum().backward();
+        assert_near!(grads.wrt(x), Vector::bra(vec![1.0, 1.0f64.exp()]), 1e-12);
+    }
+
+    #[test]
+    fn unused_var() {
+        let graph = Graph::new();
+        let x = graph.var(Vector::ket(vec![1.0, 2.0]));
+        let unused = graph.var(Vector::ket(vec![1.0, 2.0, 3.0]));
+        let grads = x.sum().backward();
+        assert_eq!(grads.wrt(unused), Tensor::zeros(vec![3, 1]));
+    }
+}
@@ -0,0 +1,60 @@
+use num::Float;
+use std::iter::Sum;
+use tensor::Tensor;
+use super::{Graph, Var};
+
+/// Value of a scalar function written over `Var`s at `point`
+pub fn value<T, F>(f: F, point: &Tensor<T>) -> T
+where
+    T: Float + Sum + Send + Sync,
+    F: for<'g> Fn(Var<'g, T>) -> Var<'g, T>
+{
+    let graph = Graph::new();
+    f(graph.var(point.clone())).value().data[0]
+}
+
+/// Exact gradient of a scalar function at `point`, with the shape of `point`
+pub fn grad<T, F>(f: F, point: &Tensor<T>) -> Tensor<T>
+where
+    T: Float + Sum + Send + Sync,
+    F: for<'g> Fn(Var<'g, T>) -> Var<'g, T>
+{
+    value_and_grad(f, point).1
+}
+
+/// Value and exact gradient from one forward and one backward pass
+pub fn value_and_grad<T, F>(f: F, point: &Tensor<T>) -> (T, Tensor<T>)
+where
+    T: Float + Sum + Send + Sync,
+    F: for<'g> Fn(Var<'g, T>) -> Var<'g, T>
+{
+    let graph = Graph::new();
+    let x = graph.var(point.clone());
+    let y = f(x);
+    assert!(y.value().is_scalar(), "Gradient needs a scalar function: shape = {:?}", y.value().shape);
+    (y.value().data[0], y.backward().wrt(x))
+}
+
+#[cfg(test)]
+mod tests {
+    use tensor::{Scalar, Vector, assert_near};
+    use super::{grad, value_and_grad, Var};
+
+    #[test]
+    fn tanh() {
+        let (value, gradient) = value_and_grad(|w: Var<f64>| w.tanh(), &Scalar::new(0.0));
+        assert_eq!(value, 0.0);
+        assert_eq!(gradient, Scalar::new(1.0));
+    }
+
+    fn quadratic(x: Var<f64>) -> Var<f64> {
+        let shift = x.graph().constant(Vector::ket(vec![1.0, -2.0]));
+        (x - shift).powi(2).sum()
+    }
+
+    #[test]
+    fn quadratic_grad() {
+        let recieved = grad(quadratic, &Vector::ket(vec![3.0, 3.0]));
+        assert_near!(recieved, Vector::ket(vec![4.0, 10.0]), 1e-12);
+    }
+}
@@ -0,0 +1,75 @@
+use num::Float;
+use std::cell::RefCell;
+use tensor::Tensor;
+use super::Var;
+
+/// Operation that produced a node, operands are indices of earlier nodes on the tape
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Op {
+    Leaf,
+    Add(usize, usize),
+    Sub(usize, usize),
+    Mul(usize, usize),
+    Neg(usize),
+    Dot(usize, usize),
+    Exp(usize),
+    Ln(usize),
+    Tanh(usize),
+    Sigmoid(usize),
+    Powi(usize, i32),
+    Sum(usize),
+    Mean(usize)
+}
+
+#[derive(Debug, Clone)]
+pub struct Node<T> where T: Float {
+    pub value: Tensor<T>,
+    pub op: Op
+}
+
+/// Tape of the forward pass. Nodes are only appended, so the tape is
+/// always in topological order and the backward pass simply walks it in reverse.
+#[derive(Debug)]
+pub struct Graph<T=f64> where T: Float {
+    pub(crate) nodes: RefCell<Vec<Node<T>>>
+}
+
+impl<T> Default for Graph<T> where T: Float {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> Graph<T> where T: Float {
+    pub fn new() -> Self {
+        Self { nodes: RefCell::new(vec![]) }
+    }
+
+    /// Input of the computation, gradients can be taken with respect to it
+    pub fn var(&self, value: Tensor<T>) -> Var<'_, T> {
+        self.push(value, Op::Leaf)
+    }
+
+    /// Same as `var`, a constant is a leaf whose gradient is simply not asked for
+    pub fn constant(&self, value: Tensor<T>) -> Var<'_, T> {
+        self.push(value, Op::Leaf)
+    }
+
+    pub fn len(&self) -> usize {
+        self.nodes.borrow().len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    pub(crate) fn push(&self, value: Tensor<T>, op: Op) -> Var<'_, T> {
+        let mut nodes = self.nodes.borrow_mut();
+        nodes.push(Node { value, op });
+        Var { graph: self, index: nodes.len() - 1 }
+    }
+
+    pub(crate) fn value(&self, index: usize) -> Tensor<T> {
+        self.nodes.borrow()[index].value.clone()
+    }
+}
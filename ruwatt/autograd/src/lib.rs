@@ -0,0 +1,9 @@
+pub mod graph;
+pub mod var;
+pub mod backward;
+pub mod functions;
+
+pub use graph::*;
+pub use var::*;
+pub use backward::*;
+pub use functions::*;
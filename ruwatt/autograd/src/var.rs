@@ -0,0 +1,145 @@
+use num::Float;
+use std::iter::Sum;
+use std::ops;
+use tensor::{dot, Scalar, Tensor};
+use super::{Graph, Op};
+
+/// Handle of a node in a `Graph`
+#[derive(Debug)]
+pub struct Var<'g, T> where T: Float {
+    pub(crate) graph: &'g Graph<T>,
+    pub(crate) index: usize
+}
+
+impl<'g, T> Clone for Var<'g, T> where T: Float {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<'g, T> Copy for Var<'g, T> where T: Float {}
+
+impl<'g, T> Var<'g, T> where T: Float + Sum + Send + Sync {
+    pub fn value(&self) -> Tensor<T> {
+        self.graph.value(self.index)
+    }
+
+    pub fn graph(&self) -> &'g Graph<T> {
+        self.graph
+    }
+
+    pub fn dot(self, other: Var<'g, T>) -> Var<'g, T> {
+        self.binary(other, Op::Dot(self.index, other.index), dot)
+    }
+
+    pub fn exp(self) -> Var<'g, T> {
+        self.unary(Op::Exp(self.index), T::exp)
+    }
+
+    pub fn ln(self) -> Var<'g, T> {
+        self.unary(Op::Ln(self.index), T::ln)
+    }
+
+    pub fn tanh(self) -> Var<'g, T> {
+        self.unary(Op::Tanh(self.index), T::tanh)
+    }
+
+    pub fn sigmoid(self) -> Var<'g, T> {
+        self.unary(Op::Sigmoid(self.index), |x| T::one() / (T::one() + (-x).exp()))
+    }
+
+    pub fn powi(self, n: i32) -> Var<'g, T> {
+        self.unary(Op::Powi(self.index, n), |x| x.powi(n))
+    }
+
+    /// Sum of all elements as a scalar
+    pub fn sum(self) -> Var<'g, T> {
+        let value = self.value().data.iter().fold(T::zero(), |sum, &x| sum + x);
+        self.graph.push(Scalar::new(value), Op::Sum(self.index))
+    }
+
+    /// Mean of all elements as a scalar
+    pub fn mean(self) -> Var<'g, T> {
+        let value = self.value();
+        let count = T::from(value.data.len()).unwrap();
+        let mean = value.data.iter().fold(T::zero(), |sum, &x| sum + x) / count;
+        self.graph.push(Scalar::new(mean), Op::Mean(self.index))
+    }
+
+    fn unary(self, op: Op, f: impl Fn(T) -> T) -> Var<'g, T> {
+        let mut value = self.value();
+        value.apply(f);
+        self.graph.push(value, op)
+    }
+
+    fn binary(self, other: Var<'g, T>, op: Op, f: impl Fn(&Tensor<T>, &Tensor<T>) -> Tensor<T>) -> Var<'g, T> {
+        assert!(std::ptr::eq(self.graph, other.graph), "Vars belong to different graphs");
+        let value = f(&self.value(), &other.value());
+        self.graph.push(value, op)
+    }
+}
+
+impl<'g, T> ops::Add for Var<'g, T> where T: Float + Sum + Send + Sync {
+    type Output = Var<'g, T>;
+
+    fn add(self, other: Var<'g, T>) -> Var<'g, T> {
+        self.binary(other, Op::Add(self.index, other.index), |a, b| a + b)
+    }
+}
+
+impl<'g, T> ops::Sub for Var<'g, T> where T: Float + Sum + Send + Sync {
+    type Output = Var<'g, T>;
+
+    fn sub(self, other: Var<'g, T>) -> Var<'g, T> {
+        self.binary(other, Op::Sub(self.index, other.index), |a, b| a - b)
+    }
+}
+
+impl<'g, T> ops::Mul for Var<'g, T> where T: Float + Sum + Send + Sync {
+    type Output = Var<'g, T>;
+
+    fn mul(self, other: Var<'g, T>) -> Var<'g, T> {
+        self.binary(other, Op::Mul(self.index, other.index), |a, b| a * b)
+    }
+}
+
+impl<'g, T> ops::Neg for Var<'g, T> where T: Float + Sum + Send + Sync {
+    type Output = Var<'g, T>;
+
+    fn neg(self) -> Var<'g, T> {
+        self.unary(Op::Neg(self.index), |x| -x)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use tensor::{Matrix, Scalar, Vector};
+    use super::super::Graph;
+
+    #[test]
+    fn forward() {
+        let graph = Graph::new();
+        let x = graph.var(Vector::ket(vec![1.0, 2.0]));
+        let w = graph.var(Vector::bra(vec![3.0, 4.0]));
+        assert_eq!(w.dot(x).value(), Matrix::square(vec![11.0]));
+        assert_eq!((x * x).sum().value(), Scalar::new(5.0));
+        assert_eq!((x - x).powi(2).mean().value(), Scalar::new(0.0));
+        assert_eq!(graph.len(), 8);
+    }
+
+    #[test]
+    #[should_panic(expected = "Vars belong to different graphs")]
+    fn different_graphs() {
+        let graph1 = Graph::new();
+        let graph2 = Graph::new();
+        let _ = graph1.var(Scalar::new(1.0)) + graph2.var(Scalar::new(1.0));
+    }
+
+    #[test]
+    #[should_panic(expected = "Vars belong to different graphs")]
+    fn dot_different_graphs() {
+        let graph1 = Graph::new();
+        let graph2 = Graph::new();
+        let _ = graph1.var(Scalar::new(1.0)).dot(graph2.var(Scalar::new(1.0)));
+    }
+}
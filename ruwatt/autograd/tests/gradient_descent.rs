@@ -0,0 +1,21 @@
+use autograd::{grad, value, Var};
+use optimization::{GradientDescent, Optimizer, Problem};
+use tensor::{Tensor, Vector, assert_near};
+
+fn cost(w: Var<f64>) -> Var<f64> {
+    let graph = w.graph();
+    let x = graph.constant(Tensor { shape: vec![4, 2], data: vec![1.0, 0.0, 1.0, 1.0, 1.0, 2.0, 1.0, 3.0] });
+    let y = graph.constant(Vector::ket(vec![1.0, 3.0, 5.0, 7.0]));
+    (x.dot(w) - y).powi(2).mean()
+}
+
+#[test]
+fn gradient_descent_autograd() {
+    let func = |w: &Tensor| value(cost, w);
+    let gradient = |w: &Tensor| grad(cost, w);
+    let problem = Problem::new(func, Vector::ket(vec![0.0, 0.0])).with_gradient(gradient);
+    let mut optimizator = GradientDescent::default();
+    let result = optimizator.minimize(&problem);
+    assert_near!(result.arg, Vector::ket(vec![1.0, 2.0]), 0.01);
+    assert!(result.value < 1e-4);
+}
@@ -2,7 +2,7 @@ use std::collections::HashMap;
 
 use num::Float;
 use super::{DataFrame, FrameDataCell};
-use tensor::IndexError;
+use tensor::TensorError;
 
 impl<T> DataFrame<T> where T: Float {
     pub fn shape(&self) -> (usize, usize) {
@@ -21,20 +21,20 @@ impl<T> DataFrame<T> where T: Float {
         self.data.len()
     }
 
-    pub fn row(&self, index: usize) -> Result<Vec<FrameDataCell<T>>, IndexError> {
+    pub fn row(&self, index: usize) -> Result<Vec<FrameDataCell<T>>, TensorError> {
         if index < self.row_count() {
             Ok(self.data[index].clone())
         } else {
-            Err(IndexError::IndexOutOfBounds)
+            Err(TensorError::IndexOutOfRange { index, size: self.row_count() })
         }
     }
 
-    pub fn col(&self, index: usize) -> Result<Vec<FrameDataCell<T>>, IndexError> {
+    pub fn col(&self, index: usize) -> Result<Vec<FrameDataCell<T>>, TensorError> {
         if index < self.col_count() {
             let result = self.data.iter().map(|val| val[index].clone()).collect();
             Ok(result)
         } else {
-            Err(IndexError::IndexOutOfBounds)
+            Err(TensorError::IndexOutOfRange { index, size: self.col_count() })
         }
     }
 
@@ -58,7 +58,7 @@ impl<T> DataFrame<T> where T: Float {
     }
 
     //TODO unit test, naming, iterator
-    pub fn row_(&self, index: usize) -> Result<HashMap<&str, FrameDataCell<T>>, IndexError> {
+    pub fn row_(&self, index: usize) -> Result<HashMap<&str, FrameDataCell<T>>, TensorError> {
         let result: HashMap<&str, FrameDataCell<T>> = self.row(index)?.iter()
             .zip(self.headers.iter())
             .map(|(cell, header)| (header.name.as_str(), cell.clone()))
@@ -1,17 +1,17 @@
 use num::Float;
-use optimization::{GradientDescent, StepSize};
+use optimization::{GradientDescent, Optimizer, Problem};
 use std::{fmt::Debug, iter::Sum};
 use tensor::{assert_matrix, dot, Tensor, Vector};
 use super::BLCMethod;
 
 /// Binary Linear Classification
-pub struct BLC<'a, T=f64> where T: Float + Debug {
+pub struct BLC<T=f64, O=GradientDescent<'static, T>> where T: Float + Debug {
     pub coef: Tensor<T>,
     pub method: BLCMethod,
-    pub optimizator: GradientDescent<'a, T>
+    pub optimizator: O
 }
 
-impl<'a, T> Default for BLC<'a, T> where T: Float + Debug {
+impl<T, O> Default for BLC<T, O> where T: Float + Debug, O: Default {
     fn default() -> Self {
         Self {
             coef: Tensor::empty(),
@@ -21,41 +21,35 @@ impl<'a, T> Default for BLC<'a, T> where T: Float + Debug {
     }
 }
 
-impl<'a, T> BLC<'a, T> where T: Float + Debug + Sum {
+impl<T, O> BLC<T, O> where T: Float + Debug + Sum + Send + Sync, O: Optimizer<T> + Clone {
     pub fn fit(&mut self, x: &Tensor<T>, y: &Tensor<T>){
         self.validate_fit(x, y);
-        let f = |w: &Tensor<T>| self.cost_function_wrapper(w, &x, &y);
-        let mut optimizator = GradientDescent {
-            func: &f,
-            start_point: Vector::bra(vec![T::one(); x.col_count() + 1]),
-            ..self.optimizator.clone()
-        };
-        optimizator.run();
-        let result = optimizator.result.unwrap();
+        let method = self.method.clone();
+        let f = |w: &Tensor<T>| Self::cost_function_wrapper(&method, w, &x, &y);
+        let problem = Problem::new(f, Vector::bra(vec![T::one(); x.col_count() + 1]));
+        let result = self.optimizator.clone().minimize(&problem);
         self.coef.append_row(result.arg);
     }
 
     pub fn predict(&mut self, x: &Tensor<T>) -> Tensor<T> {
         assert!(self.trained(), "Model is not trained");
-        let mut data = vec![];
-        x.rows().for_each(|item| {
-            let x_modified = item.prepend_one().to_ket();
-            let activation = |v: T| self.method.activation(v);
-            let value = activation(dot(&self.coef, &x_modified).to_scalar()).round();
-            data.push(value)
-        });
-        Vector::ket(data)
+        let mut result = Self::linear_combination(&self.coef, x);
+        result.apply(|value| self.method.activation(value).round());
+        result
     }
 
-    fn cost_function_wrapper(&self, w: &Tensor<T>, x: &Tensor<T>, y: &Tensor<T>) -> T {
+    fn linear_combination(w: &Tensor<T>, x: &Tensor<T>) -> Tensor<T> {
+        let weights = Vector::ket(w.data[1..].to_vec());
+        dot(x, &weights) + w.get_v(0)
+    }
+
+    fn cost_function_wrapper(method: &BLCMethod, w: &Tensor<T>, x: &Tensor<T>, y: &Tensor<T>) -> T {
         let count = T::from(y.data.len()).unwrap();
-        x.rows()
+        Self::linear_combination(w, x).data.iter()
             .zip(y.data.iter())
-            .map(|(x_test, &y_test)| {
-                let x_modified = x_test.prepend_one().to_ket();
-                let value = dot(&w, &x_modified).to_scalar();
-                let activation = |v: T| self.method.activation(v);
-                match self.method {
+            .map(|(&value, &y_test)| {
+                let activation = |v: T| method.activation(v);
+                match method {
                     BLCMethod::LeastSquaresSigmoid | BLCMethod::LeastSquaresTanh => T::powi(activation(value) - y_test, 2),
                     BLCMethod::CrossEntropy => -(y_test * T::ln(activation(value)) + (T::one() - y_test) * T::ln(T::one() - activation(value))),
                     BLCMethod::Softmax => T::ln(T::one() + T::exp(-y_test * value))
@@ -77,9 +71,8 @@ impl<'a, T> BLC<'a, T> where T: Float + Debug + Sum {
         let condition =  y.data.iter().all(|x| allowed_values.contains(x));
         assert!(condition, "{} is only applicable for y values {:?}", self.method, allowed_values);
 
-        if self.optimizator.step_size == StepSize::Newton &&
-           self.optimizator.regularization.is_none() {
-            println!("Warning! Newton step size needs regularization here")
+        if self.optimizator.is_second_order() && !self.optimizator.is_regularized() {
+            println!("Warning! Second order methods need regularization here")
         }
     }
 }
@@ -4,14 +4,14 @@ use tensor::{assert_ket, dot, Tensor};
 
 use super::sigmoid;
 
-fn log_error<T>(x: &Tensor<T>, w: &Tensor<T>, y: T) -> T where T: Float + Sum {
+fn log_error<T>(x: &Tensor<T>, w: &Tensor<T>, y: T) -> T where T: Float + Sum + Send + Sync {
     let predict = sigmoid(dot(&x.tr(), w).to_scalar());
     let _1 = T::one();
     -y * T::ln(predict) - (_1 - y) * T::ln(_1 - predict)
 }
 
 /*Is used for values 0, 1 */
-pub fn cros_entropy_cost<T>(x: &Tensor<T>, w: &Tensor<T>, y: &Tensor<T>) -> T where T: Float + Sum {
+pub fn cros_entropy_cost<T>(x: &Tensor<T>, w: &Tensor<T>, y: &Tensor<T>) -> T where T: Float + Sum + Send + Sync {
     assert_ket!(x);
     assert_ket!(w);
     assert_ket!(y);
@@ -2,59 +2,66 @@ use num::Float;
 use std::fmt::Debug;
 use std::iter::Sum;
 
-use optimization::{StepSize, GradientDescent};
-use tensor::{ dot, Tensor, Vector, assert_matrix };
+use optimization::{GradientDescent, Optimizer, Problem};
+use tensor::{ dot, dot_view, lstsq, Matrix, Tensor, Vector, assert_matrix };
 
-use super::LinearRegressionMethod;
+use super::{LinearRegressionMethod, LinearRegressionSolver};
 
-pub struct LinearRegressionModel<'a, T=f64> where T: Float + Sum + Debug {
+pub struct LinearRegressionModel<T=f64, O=GradientDescent<'static, T>> where T: Float + Sum + Debug {
     pub feature_count: usize,
     pub coef: Tensor<T>,
     pub method: LinearRegressionMethod,
-    pub optimizator: GradientDescent<'a, T>
+    pub solver: LinearRegressionSolver,
+    pub optimizator: O
 }
 
-impl<'a, T> Default for LinearRegressionModel<'a, T> where T: Float + Sum+ Debug {
+impl<T, O> Default for LinearRegressionModel<T, O> where T: Float + Sum+ Debug, O: Default {
     fn default() -> Self {
         Self {
             feature_count: 0,
             coef: Tensor::empty(),
             method: LinearRegressionMethod::LeastSquares,
+            solver: LinearRegressionSolver::Optimizator,
             optimizator: Default::default()
         }
     }
 }
 
-impl<'a, T> LinearRegressionModel<'a, T> where T: Float + Send + Sync + Sum + Debug + 'static {
+impl<T, O> LinearRegressionModel<T, O> where T: Float + Send + Sync + Sum + Debug + 'static, O: Optimizer<T> + Clone {
 
     pub fn fit(&mut self, x: &Tensor<T>, y: &Tensor<T>) {
         self.validate_fit(x, y);
         self.feature_count = x.col_count();
+        match self.solver {
+            LinearRegressionSolver::Optimizator => self.fit_optimizator(x, y),
+            LinearRegressionSolver::Lstsq => self.fit_lstsq(x, y)
+        }
+    }
 
+    fn fit_optimizator(&mut self, x: &Tensor<T>, y: &Tensor<T>) {
         let cost_function_wrappers = self.cost_function_wrappers(y.col_count());
         for cost_function_wrapper in cost_function_wrappers.iter() {
             let f = |w: &Tensor<T>| cost_function_wrapper(w, &x, &y);
-            let mut optimizator = GradientDescent {
-                func: &f,
-                start_point: Vector::bra(vec![T::one(); x.col_count() + 1]),
-                ..self.optimizator.clone()
-            };
-            optimizator.run();
-            let result = optimizator.result.unwrap();
+            let problem = Problem::new(f, Vector::bra(vec![T::one(); x.col_count() + 1]));
+            let result = self.optimizator.clone().minimize(&problem);
             self.coef.append_row(result.arg);
         }
     }
 
+    fn fit_lstsq(&mut self, x: &Tensor<T>, y: &Tensor<T>) {
+        let ones = Tensor::ones(vec![x.row_count(), 1]);
+        let x_modified = Matrix::concat_h(ones, x.clone());
+        let coef = lstsq(&x_modified, y).expect("Lstsq solver needs linearly independent features");
+        self.coef = coef.tr();
+    }
+
     pub fn predict(&mut self, x: &Tensor<T>) -> Tensor<T> {
         assert!(self.trained(), "Model is not trained");
         assert_eq!(self.feature_count, x.col_count(), "Feature count must be {}", self.feature_count);
-        let mut result = Tensor::empty();
-        x.rows().for_each(|item| {
-            let x_modified = item.prepend_one().to_ket();
-            let row = dot(&self.coef, &x_modified).to_bra();
-            result.append_row(row)
-        });
-        result
+        let coef = self.coef.view();
+        let bias = coef.col(0).tr();
+        let weights = coef.slice(&[0..self.coef.row_count(), 1..self.feature_count + 1]);
+        &dot_view(&x.view(), &weights.tr()) + &bias
     }
 
     fn cost_function_wrappers(&self, count: usize) -> Vec<Box<dyn Fn(&Tensor<T>, &Tensor<T>, &Tensor<T>) -> T + Send + Sync>>{
@@ -62,15 +69,12 @@ impl<'a, T> LinearRegressionModel<'a, T> where T: Float + Send + Sync + Sum + De
                 .map(|index| {
                     let method = self.method.clone();
                     Box::new(move |w: &Tensor<T>, x: &Tensor<T>, y: &Tensor<T>| {
-                        x.rows()
-                            .zip(y.rows())
-                            .map(|(x_test, y_test)| {
-                                let x_modified = x_test.prepend_one().to_ket();
-                                let value = dot(&w, &x_modified).to_scalar() - y_test.get_v(index);
-                                match method {
-                                    LinearRegressionMethod::Abs => T::abs(value),
-                                    LinearRegressionMethod::LeastSquares => T::powi(value, 2) 
-                                }
+                        let weights = Vector::ket(w.data[1..].to_vec());
+                        let residual = &(dot(x, &weights) + w.get_v(0)) - &y.col_view(index);
+                        residual.data.iter()
+                            .map(|&value| match method {
+                                LinearRegressionMethod::Abs => T::abs(value),
+                                LinearRegressionMethod::LeastSquares => T::powi(value, 2) 
                             })
                             .sum()
                     }) as Box<dyn Fn(&Tensor<T>, &Tensor<T>, &Tensor<T>) -> T + Send + Sync>
@@ -87,7 +91,11 @@ impl<'a, T> LinearRegressionModel<'a, T> where T: Float + Send + Sync + Sum + De
         assert_matrix!(y);
         assert_eq!(x.row_count(), y.row_count(), "Count of x train not correspond to y");
 
-        if LinearRegressionMethod::Abs == self.method && StepSize::Newton == self.optimizator.step_size {
+        if LinearRegressionSolver::Lstsq == self.solver {
+            assert!(LinearRegressionMethod::LeastSquares == self.method, "Lstsq solver is only applicable for LeastSquares method");
+            assert!(x.row_count() > x.col_count(), "Lstsq solver needs more samples than features: {} samples, {} features", x.row_count(), x.col_count());
+        }
+        if LinearRegressionMethod::Abs == self.method && self.optimizator.is_second_order() {
             eprintln!("Warning: Using Abs cost function with Newton step size is not recommended.");
         } 
     }
@@ -96,12 +104,11 @@ impl<'a, T> LinearRegressionModel<'a, T> where T: Float + Send + Sync + Sum + De
 #[cfg(test)]
 mod tests {
     use rand::prelude::*;
-    use optimization::StepSize;
-    use super::{LinearRegressionModel, LinearRegressionMethod, GradientDescent};
-    use tensor::{assert_near, Matrix, Tensor};
+    use optimization::{gradient, gradient_exact, DifferenceScheme, Dual, NelderMead, StepSize, StopCriteria, UpdateRule};
+    use super::{LinearRegressionModel, LinearRegressionMethod, LinearRegressionSolver, GradientDescent};
+    use tensor::{assert_near, Matrix, Tensor, Vector};
 
-    fn generate_x(count: usize, x_min: f64, x_max: f64) -> Vec<Vec<f64>>{
-        let mut rng = rand::thread_rng();
+    fn generate_x(rng: &mut StdRng, count: usize, x_min: f64, x_max: f64) -> Vec<Vec<f64>>{
         (0..count)
             .map(|_| vec![
                 rng.gen_range(x_min..x_max),
@@ -110,8 +117,7 @@ mod tests {
             .collect()
     }
 
-    fn calc_y(x: &Vec<Vec<f64>>) -> Vec<Vec<f64>>{
-        let mut rng = rand::thread_rng();
+    fn calc_y(rng: &mut StdRng, x: &Vec<Vec<f64>>) -> Vec<Vec<f64>>{
         x.iter()
             .map(|x_vec| vec![
                 2.5 * x_vec[0] + 3.5* x_vec[1]  + 1.7 + rng.gen_range(-1.0..1.0),
@@ -122,10 +128,11 @@ mod tests {
     }
 
     fn create_train_test(train_size: usize, test_size: usize) -> (Tensor, Tensor, Tensor, Tensor) {
-        let x_train = generate_x(train_size, 0.0, 10.0);
-        let y_train = calc_y(&x_train);
-        let x_test = generate_x(test_size, 0.0, 10.0);
-        let y_test = calc_y(&x_test);
+        let mut rng = StdRng::seed_from_u64(42);
+        let x_train = generate_x(&mut rng, train_size, 0.0, 10.0);
+        let y_train = calc_y(&mut rng, &x_train);
+        let x_test = generate_x(&mut rng, test_size, 0.0, 10.0);
+        let y_test = calc_y(&mut rng, &x_test);
 
         let x_train = Matrix::new(x_train);
         let y_train = Matrix::new(y_train);
@@ -164,4 +171,114 @@ mod tests {
                 assert_near!(predict, test, 2.0);
         });
     }
+
+    #[test]
+    fn linear_regression_backtracking() {
+        let (x_train, y_train, x_test, y_test) = create_train_test(100, 5);
+        let mut model = LinearRegressionModel {
+            optimizator: GradientDescent {
+                step_count: 300,
+                step_size: StepSize::Backtracking { c: 1e-4, rho: 0.5 },
+                difference_scheme: DifferenceScheme::Central,
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        model.fit(&x_train, &y_train);
+        let y_predict = model.predict(&x_test);
+
+        y_predict.rows()
+            .zip(y_test.rows())
+            .for_each(|(predict, test)| {
+                assert_near!(predict, test, 2.0);
+        });
+    }
+
+    #[test]
+    fn linear_regression_rms_prop() {
+        let (x_train, y_train, x_test, y_test) = create_train_test(100, 5);
+        let mut model = LinearRegressionModel {
+            optimizator: GradientDescent {
+                step_count: 1000,
+                step_size: StepSize::Decrement(1.0),
+                update_rule: UpdateRule::rms_prop(),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        model.fit(&x_train, &y_train);
+        let y_predict = model.predict(&x_test);
+
+        y_predict.rows()
+            .zip(y_test.rows())
+            .for_each(|(predict, test)| {
+                assert_near!(predict, test, 2.0);
+        });
+    }
+
+    #[test]
+    fn linear_regression_abs_nelder_mead() {
+        let (x_train, y_train, x_test, y_test) = create_train_test(100, 5);
+        let mut model = LinearRegressionModel {
+            method: LinearRegressionMethod::Abs,
+            optimizator: NelderMead {
+                stop_criteria: StopCriteria { arg_change: Some(1e-6), ..Default::default() },
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        model.fit(&x_train, &y_train);
+        let y_predict = model.predict(&x_test);
+
+        y_predict.rows()
+            .zip(y_test.rows())
+            .for_each(|(predict, test)| {
+                assert_near!(predict, test, 2.0);
+        });
+    }
+
+    #[test]
+    fn linear_regression_lstsq() {
+        let (x_train, y_train, x_test, y_test) = create_train_test(10, 5);
+        let mut model: LinearRegressionModel = LinearRegressionModel {
+            solver: LinearRegressionSolver::Lstsq,
+            ..Default::default()
+        };
+        model.fit(&x_train, &y_train);
+        assert_eq!(model.coef.shape, vec![3, 3]);
+        let y_predict = model.predict(&x_test);
+
+        y_predict.rows()
+            .zip(y_test.rows())
+            .for_each(|(predict, test)| {
+                assert_near!(predict, test, 2.0);
+        });
+    }
+
+    #[test]
+    #[should_panic(expected = "Lstsq solver needs more samples than features")]
+    fn linear_regression_lstsq_too_few_samples() {
+        let (x_train, y_train, _, _) = create_train_test(2, 1);
+        let mut model: LinearRegressionModel = LinearRegressionModel {
+            solver: LinearRegressionSolver::Lstsq,
+            ..Default::default()
+        };
+        model.fit(&x_train, &y_train);
+    }
+
+    #[test]
+    fn cost_gradient_exact() {
+        let (x_train, y_train, _, _) = create_train_test(10, 1);
+        let point = Vector::bra(vec![0.5, 1.0, -1.0]);
+
+        let model = LinearRegressionModel::<f64>::default();
+        let cost = &model.cost_function_wrappers(3)[1];
+        let expected = gradient(&|w: &Tensor| cost(w, &x_train, &y_train), &point, 1e-7);
+
+        let model = LinearRegressionModel::<Dual<f64>>::default();
+        let cost = &model.cost_function_wrappers(3)[1];
+        let (x, y) = (Dual::constants(&x_train), Dual::constants(&y_train));
+        let recieved = gradient_exact(&|w: &Tensor<Dual<f64>>| cost(w, &x, &y), &point);
+        assert_near!(expected, recieved, 1e-2);
+    }
 }
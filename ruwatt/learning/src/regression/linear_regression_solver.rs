@@ -0,0 +1,5 @@
+#[derive(PartialEq, Clone)]
+pub enum LinearRegressionSolver {
+    Optimizator,
+    Lstsq,
+}
@@ -1,5 +1,7 @@
 pub mod linear_regression_model;
 pub mod linear_regression_method;
+pub mod linear_regression_solver;
 
 pub use linear_regression_model::*;
 pub use linear_regression_method::*;
+pub use linear_regression_solver::*;
@@ -2,7 +2,7 @@ use std::{collections::HashMap, error::Error};
 
 use data_frame::{ApplyChanger, ApplyError, DataFrame, FrameDataCell, FrameHeader};
 use learning::{BLCMethod, BLC, ConfusionMatrix};
-use optimization::GradientDescent;
+use optimization::{GradientDescent, StepSize, UpdateRule};
 use statistics::Statistics;
 use tensor::{Matrix, Tensor};
 
@@ -156,3 +156,30 @@ fn softmax() -> Result<(), Box<dyn Error>> {
     assert_eq!(recieved, expected);
     Ok(()) 
 }
+
+#[test]
+fn cross_entropy_adam() -> Result<(), Box<dyn Error>> {
+    let allowed_values = vec![0.0, 1.0];
+    let (x_train, y_train, x_test, y_test) = get_data(allowed_values)?;
+
+    let mut model = BLC {
+        method: BLCMethod::CrossEntropy,
+        optimizator: GradientDescent {
+            step_count: 100,
+            step_size: StepSize::Fixed(0.1),
+            update_rule: UpdateRule::adam(),
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+    model.fit(&x_train, &y_train);
+    let y_predict = model.predict(&x_test);
+
+    let recieved = ConfusionMatrix::new(&y_test , &y_predict).to_tensor();
+    let expected = Matrix::new(vec![
+        vec![ 17.0, 1.0],
+        vec![ 0.0, 16.0],
+    ]);
+    assert_eq!(recieved, expected);
+    Ok(()) 
+}
@@ -2,9 +2,9 @@ use std::{collections::HashMap, error::Error};
 
 use data_frame::{ApplyChanger, ApplyError, DataFrame, FrameDataCell};
 use learning::{BLCMethod, BLC, ConfusionMatrix};
-use optimization::GradientDescent;
+use optimization::{GradientDescent, GradientDescentRegularization, StepSize};
 use statistics::Statistics;
-use tensor::Matrix;
+use tensor::{Matrix, Tensor};
 
 fn convert_class(value: &FrameDataCell) -> Result<FrameDataCell, ApplyError> {
     if let FrameDataCell::Number(value) = value {
@@ -22,8 +22,7 @@ fn convert_class(value: &FrameDataCell) -> Result<FrameDataCell, ApplyError> {
     }
 }
 
-#[test]
-fn breast_cancer_wisconsin() -> Result<(), Box<dyn Error>> {
+fn load_data() -> Result<(Tensor, Tensor, Tensor, Tensor), Box<dyn Error>> {
     let mut df = DataFrame::<f64>::from_csv("../data/breast_cancer_wisconsin.csv", None)?;
     df.drop("id");
     df.remove_na();
@@ -46,6 +45,12 @@ fn breast_cancer_wisconsin() -> Result<(), Box<dyn Error>> {
     assert_eq!(y_train.shape, vec![546, 1]);
     assert_eq!(x_test.shape, vec![137, 9]);
     assert_eq!(y_test.shape, vec![137, 1]);
+    Ok((x_train, y_train, x_test, y_test))
+}
+
+#[test]
+fn breast_cancer_wisconsin() -> Result<(), Box<dyn Error>> {
+    let (x_train, y_train, x_test, y_test) = load_data()?;
 
     let mut model = BLC {
         method: BLCMethod::Softmax,
@@ -67,4 +72,29 @@ fn breast_cancer_wisconsin() -> Result<(), Box<dyn Error>> {
     ]);
     assert_eq!(recieved, expected);
     Ok(()) 
-}
\ No newline at end of file
+}
+
+#[test]
+fn breast_cancer_wisconsin_newton_cg() -> Result<(), Box<dyn Error>> {
+    let (x_train, y_train, x_test, y_test) = load_data()?;
+    let mut model = BLC {
+        method: BLCMethod::Softmax,
+        optimizator: GradientDescent {
+            step_count: 10,
+            step_size: StepSize::NewtonCG(10),
+            betta: 0.0,
+            regularization: Some(GradientDescentRegularization::MaxLength(100.0)),
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+    model.fit(&x_train, &y_train);
+
+    let y_predict = model.predict(&x_test);
+    let errors = y_predict.data.iter()
+        .zip(y_test.data.iter())
+        .filter(|(predict, test)| predict != test)
+        .count();
+    assert!(errors <= 5);
+    Ok(())
+}
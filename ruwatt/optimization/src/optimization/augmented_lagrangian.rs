@@ -0,0 +1,210 @@
+use num::Float;
+use std::{fmt::Debug, time::Instant};
+use tensor::Tensor;
+use super::{gradient_with, hessian_with, DifferenceScheme, OptimizationResult, Optimizer, Problem, RunSummary, StopReason};
+
+type Constraint<'a, T> = Box<dyn Fn(&Tensor<T>) -> T + 'a>;
+
+/// Minimize f(w) subject to gᵢ(w) ≤ 0 and hⱼ(w) = 0
+pub struct ConstrainedProblem<'a, T> where T: Float {
+    pub problem: Problem<'a, T>,
+    pub inequalities: Vec<Constraint<'a, T>>,
+    pub equalities: Vec<Constraint<'a, T>>
+}
+
+impl<'a, T> ConstrainedProblem<'a, T> where T: Float {
+    pub fn new(problem: Problem<'a, T>) -> Self {
+        Self { problem, inequalities: vec![], equalities: vec![] }
+    }
+
+    /// Adds g(w) ≤ 0
+    pub fn with_inequality(mut self, constraint: impl Fn(&Tensor<T>) -> T + 'a) -> Self {
+        self.inequalities.push(Box::new(constraint));
+        self
+    }
+
+    /// Adds h(w) = 0
+    pub fn with_equality(mut self, constraint: impl Fn(&Tensor<T>) -> T + 'a) -> Self {
+        self.equalities.push(Box::new(constraint));
+        self
+    }
+
+    /// Largest of max(gᵢ(w), 0) and |hⱼ(w)|, zero at feasible points
+    pub fn violation(&self, arg: &Tensor<T>) -> T {
+        let inequalities = self.inequalities.iter().map(|g| g(arg).max(T::zero()));
+        let equalities = self.equalities.iter().map(|h| h(arg).abs());
+        inequalities.chain(equalities).fold(T::zero(), T::max)
+    }
+}
+
+/// Optimum of a constrained run with the Lagrange multipliers of its constraints
+#[derive(Clone, Debug)]
+pub struct ConstrainedResult<T> where T: Float {
+    pub result: OptimizationResult<T>,
+    pub inequality_multipliers: Vec<T>,
+    pub equality_multipliers: Vec<T>,
+    pub violation: T
+}
+
+/// Augmented Lagrangian method: every outer step minimizes
+/// f + Σ λⱼhⱼ + μ/2·Σ hⱼ² + 1/(2μ)·Σ (max(0, νᵢ + μgᵢ)² - νᵢ²)
+/// with `optimizer` from the previous point, then updates λⱼ += μhⱼ and νᵢ = max(0, νᵢ + μgᵢ).
+/// The penalty μ grows by `penalty_growth` whenever the violation doesn't drop to a quarter.
+/// An analytic gradient or Hessian of the problem is passed on to `optimizer`,
+/// only the constraint terms are then differenced with `derivative_delta` and `difference_scheme`.
+#[derive(Clone)]
+pub struct AugmentedLagrangian<T, O> where T: Float {
+    pub optimizer: O,
+    pub outer_steps: usize,
+    pub penalty: T,
+    pub penalty_growth: T,
+    /// Constraint violation at which the run stops
+    pub tolerance: T,
+    pub derivative_delta: T,
+    pub difference_scheme: DifferenceScheme,
+    pub verbose: bool
+}
+
+impl<T, O> Default for AugmentedLagrangian<T, O> where T: Float, O: Default {
+    fn default() -> Self {
+        Self {
+            optimizer: O::default(),
+            outer_steps: 20,
+            penalty: T::from(10.0).unwrap(),
+            penalty_growth: T::from(10.0).unwrap(),
+            tolerance: T::from(1e-6).unwrap(),
+            derivative_delta: T::from(0.0001).unwrap(),
+            difference_scheme: DifferenceScheme::Central,
+            verbose: false
+        }
+    }
+}
+
+impl<T, O> AugmentedLagrangian<T, O> where T: Float + Debug, O: Optimizer<T> {
+    pub fn minimize(&mut self, constrained: &ConstrainedProblem<T>) -> ConstrainedResult<T> {
+        self.log("Augmented Lagrangian started");
+        let start = Instant::now();
+
+        let mut arg = constrained.problem.start_point.clone();
+        let mut inequality_multipliers = vec![T::zero(); constrained.inequalities.len()];
+        let mut equality_multipliers = vec![T::zero(); constrained.equalities.len()];
+        let mut penalty = self.penalty;
+        let mut violation = constrained.violation(&arg);
+        let mut reason = StopReason::StepCount;
+        let mut steps = 0;
+        constrained.problem.reset_evaluations();
+        for step in 0..self.outer_steps {
+            arg = self.minimize_lagrangian(constrained, &arg, &inequality_multipliers, &equality_multipliers, penalty);
+            steps = step + 1;
+
+            for (lambda, h) in equality_multipliers.iter_mut().zip(constrained.equalities.iter()) {
+                *lambda = *lambda + penalty * h(&arg);
+            }
+            for (nu, g) in inequality_multipliers.iter_mut().zip(constrained.inequalities.iter()) {
+                *nu = (*nu + penalty * g(&arg)).max(T::zero());
+            }
+            let violation_prev = violation;
+            violation = constrained.violation(&arg);
+            self.log(&format!("Outer step {}: violation {:?}, penalty {:?}", step, violation, penalty));
+            if violation <= self.tolerance {
+                reason = StopReason::ConstraintTolerance;
+                break;
+            }
+            if violation > violation_prev / T::from(4).unwrap() {
+                penalty = penalty * self.penalty_growth;
+            }
+        }
+        self.log(&format!("Augmented Lagrangian stopped by {:?} after {} outer steps", reason, steps));
+        let value = constrained.problem.value(&arg);
+        let summary = RunSummary { reason, steps, evaluations: constrained.problem.evaluations(), elapsed: start.elapsed() };
+        ConstrainedResult {
+            result: OptimizationResult { arg, value, summary, active_bounds: vec![] },
+            inequality_multipliers,
+            equality_multipliers,
+            violation
+        }
+    }
+
+    fn minimize_lagrangian(&mut self, constrained: &ConstrainedProblem<T>, arg: &Tensor<T>, inequality_multipliers: &[T], equality_multipliers: &[T], penalty: T) -> Tensor<T> {
+        let two = T::from(2).unwrap();
+        let (delta, scheme) = (self.derivative_delta, self.difference_scheme);
+        let constraint_terms = |w: &Tensor<T>| {
+            let equalities = constrained.equalities.iter()
+                .zip(equality_multipliers.iter())
+                .fold(T::zero(), |sum, (h, &lambda)| {
+                    let value = h(w);
+                    sum + lambda * value + penalty * value * value / two
+                });
+            let inequalities = constrained.inequalities.iter()
+                .zip(inequality_multipliers.iter())
+                .fold(T::zero(), |sum, (g, &nu)| {
+                    let shifted = (nu + penalty * g(w)).max(T::zero());
+                    sum + (shifted * shifted - nu * nu) / (two * penalty)
+                });
+            equalities + inequalities
+        };
+        let mut inner = Problem::new(|w: &Tensor<T>| constrained.problem.value(w) + constraint_terms(w), arg.clone());
+        if let Some(gradient) = &constrained.problem.gradient {
+            inner = inner.with_gradient(move |w: &Tensor<T>| &gradient(w) + &gradient_with(&constraint_terms, w, delta, scheme));
+        }
+        if let Some(hessian) = &constrained.problem.hessian {
+            inner = inner.with_hessian(move |w: &Tensor<T>| &hessian(w) + &hessian_with(&constraint_terms, w, delta, scheme));
+        }
+        self.optimizer.minimize(&inner).arg
+    }
+
+    fn log(&self, message: &str) {
+        if self.verbose {
+            println!("{}", message);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use tensor::{Matrix, Tensor, Vector, assert_near};
+    use super::super::{Bfgs, GradientDescent, Problem, StepSize, StopReason};
+    use super::{AugmentedLagrangian, ConstrainedProblem};
+
+    #[test]
+    fn equality_constraint() {
+        let f = |x: &Tensor| (x.get_v(0) - 2.0).powi(2) + (x.get_v(1) - 1.0).powi(2);
+        let constrained = ConstrainedProblem::new(Problem::new(f, Vector::ket(vec![0.0, 0.0])))
+            .with_equality(|x: &Tensor| x.get_v(0) + x.get_v(1) - 2.0);
+        let mut solver: AugmentedLagrangian<f64, Bfgs<f64>> = AugmentedLagrangian::default();
+        let result = solver.minimize(&constrained);
+        assert_eq!(result.result.summary.reason, StopReason::ConstraintTolerance);
+        assert_near!(result.result.arg, Vector::ket(vec![1.5, 0.5]), 1e-5);
+        assert!((result.equality_multipliers[0] - 1.0).abs() < 1e-4, "{:?}", result.equality_multipliers);
+    }
+
+    #[test]
+    fn inequality_constraints() {
+        let f = |x: &Tensor| x.get_v(0).powi(2) + x.get_v(1).powi(2);
+        let constrained = ConstrainedProblem::new(Problem::new(f, Vector::ket(vec![3.0, -1.0])))
+            .with_inequality(|x: &Tensor| 1.0 - x.get_v(0) - x.get_v(1))
+            .with_inequality(|x: &Tensor| x.get_v(0) - 5.0);
+        let mut solver: AugmentedLagrangian<f64, Bfgs<f64>> = AugmentedLagrangian::default();
+        let result = solver.minimize(&constrained);
+        assert_near!(result.result.arg, Vector::ket(vec![0.5, 0.5]), 1e-5);
+        assert!((result.inequality_multipliers[0] - 1.0).abs() < 1e-4, "{:?}", result.inequality_multipliers);
+        assert_eq!(result.inequality_multipliers[1], 0.0);
+        assert!(result.violation <= 1e-6);
+    }
+
+    #[test]
+    fn analytic_hessian_newton() {
+        let f = |x: &Tensor| (x.get_v(0) - 2.0).powi(2) + (x.get_v(1) - 1.0).powi(2);
+        let gradient = |x: &Tensor| Vector::ket(vec![2.0 * (x.get_v(0) - 2.0), 2.0 * (x.get_v(1) - 1.0)]);
+        let hessian = |_: &Tensor| Matrix::square(vec![2.0, 0.0, 0.0, 2.0]);
+        let problem = Problem::new(f, Vector::ket(vec![0.0, 0.0])).with_gradient(gradient).with_hessian(hessian);
+        let constrained = ConstrainedProblem::new(problem)
+            .with_equality(|x: &Tensor| x.get_v(0) + x.get_v(1) - 2.0);
+        let mut solver = AugmentedLagrangian {
+            optimizer: GradientDescent { step_size: StepSize::Newton, step_count: 20, ..Default::default() },
+            ..Default::default()
+        };
+        let result = solver.minimize(&constrained);
+        assert_near!(result.result.arg, Vector::ket(vec![1.5, 0.5]), 1e-5);
+    }
+}
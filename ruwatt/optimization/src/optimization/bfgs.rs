@@ -0,0 +1,216 @@
+use num::Float;
+use std::{fmt::Debug, iter::Sum, time::Instant};
+use tensor::{Matrix, Tensor};
+use super::conjugate_gradient::inner;
+use super::{wolfe, DifferenceScheme, ObserverRef, Progress};
+use super::{OptimizationResult, Optimizer, Problem, RunSummary, StopCriteria, StopReason, StopState};
+
+/// Broyden–Fletcher–Goldfarb–Shanno quasi-Newton method.
+/// Keeps a dense approximation of the inverse Hessian built from gradient differences
+/// and takes strong Wolfe steps along -H·∇f.
+#[derive(Clone)]
+pub struct Bfgs<'a, T> where T: Float + Debug {
+    pub step_count: usize,
+    pub stop_criteria: StopCriteria<'a, T>,
+    pub c1: T,
+    pub c2: T,
+    pub derivative_delta: T,
+    pub difference_scheme: DifferenceScheme,
+    pub observers: Vec<ObserverRef<'a, T>>,
+    pub verbose: bool
+}
+
+impl<'a, T> Default for Bfgs<'a, T> where T: Float + Debug {
+    fn default() -> Self {
+        Self {
+            step_count: 100,
+            stop_criteria: StopCriteria::default(),
+            c1: T::from(1e-4).unwrap(),
+            c2: T::from(0.9).unwrap(),
+            derivative_delta: T::from(0.0001).unwrap(),
+            difference_scheme: DifferenceScheme::Central,
+            observers: vec![],
+            verbose: false
+        }
+    }
+}
+
+impl<'a, T> Optimizer<T> for Bfgs<'a, T> where T: Float + Sum + Debug {
+    fn minimize(&mut self, problem: &Problem<T>) -> OptimizationResult<T> {
+        self.log("BFGS started");
+        let start = Instant::now();
+
+        let size = problem.start_point.data.len();
+        let mut inverse_hessian = Matrix::<T>::ident(size);
+        let mut is_identity = true;
+        let mut arg = problem.start_point.clone();
+        let mut reason = StopReason::StepCount;
+        let mut steps = 0;
+        problem.reset_evaluations();
+        let mut grad = self.gradient_at(problem, &arg);
+        let mut value = problem.value(&arg);
+        let mut progress = Progress::start(&self.observers, &arg, value);
+        for step in 0..self.step_count {
+            if grad.length() <= T::epsilon() {
+                reason = StopReason::GradientNorm;
+                break;
+            }
+            if let Some(stop) = self.stop_criteria.check_gradient(&grad) {
+                reason = stop;
+                break;
+            }
+            let mut direction = multiply(&inverse_hessian, &grad) * -T::one();
+            if inner(&direction, &grad) >= T::zero() {
+                self.log(&format!("Step {step}: not a descent direction, inverse Hessian is reset"));
+                inverse_hessian = Matrix::ident(size);
+                is_identity = true;
+                direction = &grad * -T::one();
+            }
+            let gradient = |x: &Tensor<T>| self.gradient_at(problem, x);
+            let search = wolfe(&|x: &Tensor<T>| problem.value(x), &gradient, &arg, &direction, &grad, self.c1, self.c2);
+            self.log(&format!(
+                "Step {}: step length {:?} after {} function and {} gradient evaluations",
+                step, search.alpha, search.function_evaluations, search.gradient_evaluations
+            ));
+            if !search.converged {
+                if is_identity {
+                    reason = StopReason::LineSearch;
+                    break;
+                }
+                self.log(&format!("Step {step}: line search failed, inverse Hessian is reset"));
+                inverse_hessian = Matrix::ident(size);
+                is_identity = true;
+                continue;
+            }
+
+            let grad_norm = grad.length();
+            let s = &direction * search.alpha;
+            let arg_change = s.length();
+            let next_arg = &arg + &s;
+            let next_grad = self.gradient_at(problem, &next_arg);
+            let y = &next_grad - &grad;
+            let curvature = inner(&y, &s);
+            if curvature > T::zero() {
+                if step == 0 {
+                    inverse_hessian = Matrix::ident(size) * (curvature / inner(&y, &y));
+                }
+                update_inverse_hessian(&mut inverse_hessian, &s, &y, curvature);
+                is_identity = false;
+            }
+            arg = next_arg;
+            grad = next_grad;
+            steps = step + 1;
+            let value_prev = value;
+            value = problem.value(&arg);
+            if let Some(stop) = progress.step(steps, &arg, value, grad_norm, arg_change) {
+                reason = stop;
+                break;
+            }
+            let state = StopState {
+                step,
+                arg: &arg,
+                value,
+                value_prev,
+                arg_change,
+                evaluations: problem.evaluations(),
+                elapsed: start.elapsed()
+            };
+            if let Some(stop) = self.stop_criteria.check(&state) {
+                reason = stop;
+                break;
+            }
+        }
+        self.log(&format!("BFGS stopped by {:?} after {} steps", reason, steps));
+        self.log(&format!("BFGS elapsed in {:?}", start.elapsed()));
+        progress.finish(RunSummary { reason, steps, evaluations: problem.evaluations(), elapsed: start.elapsed() }, None)
+    }
+}
+
+impl<'a, T> Bfgs<'a, T> where T: Float + Sum + Debug {
+
+    fn gradient_at(&self, problem: &Problem<T>, arg: &Tensor<T>) -> Tensor<T> {
+        problem.gradient(arg, self.derivative_delta, self.difference_scheme)
+    }
+
+    fn log(&self, message: &str) {
+        if self.verbose {
+            println!("{}", message);
+        }
+    }
+}
+
+/// H·v keeping the shape of v
+fn multiply<T>(matrix: &Tensor<T>, vector: &Tensor<T>) -> Tensor<T> where T: Float + Sum {
+    let size = vector.data.len();
+    let data = matrix.data.chunks(size)
+        .map(|row| row.iter().zip(vector.data.iter()).map(|(&a, &b)| a * b).sum())
+        .collect();
+    Tensor { shape: vector.shape.clone(), data }
+}
+
+/// H ← (I - ρ·s·yᵀ)·H·(I - ρ·y·sᵀ) + ρ·s·sᵀ, ρ = 1 / yᵀs, expanded as
+/// H + ρ²(yᵀs + yᵀHy)·s·sᵀ - ρ(Hy·sᵀ + s·(Hy)ᵀ), using symmetry of H
+fn update_inverse_hessian<T>(inverse_hessian: &mut Tensor<T>, s: &Tensor<T>, y: &Tensor<T>, curvature: T) where T: Float + Sum {
+    let size = s.data.len();
+    let rho = curvature.recip();
+    let hy = multiply(inverse_hessian, y);
+    let factor = rho * rho * (curvature + inner(y, &hy));
+    for row in 0..size {
+        for col in 0..size {
+            let (s_row, s_col) = (s.data[row], s.data[col]);
+            let correction = factor * s_row * s_col - rho * (hy.data[row] * s_col + s_row * hy.data[col]);
+            inverse_hessian.data[row * size + col] = inverse_hessian.data[row * size + col] + correction;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::RefCell;
+    use super::super::History;
+    use super::{Bfgs, Optimizer, Problem, StopCriteria, StopReason};
+    use tensor::{Tensor, Vector, assert_near};
+
+    fn f(x: &Tensor) -> f64 {
+        2.0 + x.get_v(0).powi(2) + x.get_v(1).powi(2)
+    }
+
+    fn rosenbrock(x: &Tensor) -> f64 {
+        (1.0 - x.get_v(0)).powi(2) + 100.0 * (x.get_v(1) - x.get_v(0).powi(2)).powi(2)
+    }
+
+    fn grad_rosenbrock(x: &Tensor) -> Tensor {
+        Vector::bra(vec![
+            -2.0 * (1.0 - x.get_v(0)) - 400.0 * x.get_v(0) * (x.get_v(1) - x.get_v(0).powi(2)),
+            200.0 * (x.get_v(1) - x.get_v(0).powi(2))
+        ])
+    }
+
+    #[test]
+    fn bfgs_quadratic() {
+        let problem = Problem::new(f, Vector::ket(vec![3.0, 3.0]));
+        let mut optimizator = Bfgs {
+            step_count: 10,
+            ..Default::default()
+        };
+        let result = optimizator.minimize(&problem);
+        assert!(f64::abs(result.value - 2.0) < 1e-10);
+        assert_near!(result.arg, Vector::ket(vec![0.0, 0.0]), 1e-5)
+    }
+
+    #[test]
+    fn bfgs_rosenbrock() {
+        let problem = Problem::new(rosenbrock, Vector::bra(vec![-1.2, 1.0])).with_gradient(grad_rosenbrock);
+        let history = RefCell::new(History::new());
+        let mut optimizator = Bfgs {
+            observers: vec![&history],
+            stop_criteria: StopCriteria { gradient_norm: Some(1e-8), ..Default::default() },
+            ..Default::default()
+        };
+        let result = optimizator.minimize(&problem);
+        assert_eq!(result.summary.reason, StopReason::GradientNorm);
+        assert!(result.summary.steps < 60);
+        assert_eq!(history.borrow().entries.len(), result.summary.steps + 1);
+        assert_near!(result.arg, Vector::bra(vec![1.0, 1.0]), 1e-6)
+    }
+}
@@ -0,0 +1,101 @@
+use num::Float;
+use tensor::{Tensor, Vector};
+
+/// Per coordinate box lower ≤ x ≤ upper, infinite limits leave a side open
+#[derive(Clone, Debug, PartialEq)]
+pub struct Bounds<T> where T: Float {
+    pub lower: Tensor<T>,
+    pub upper: Tensor<T>
+}
+
+/// Coordinate of a point lying on its lower or upper limit
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum ActiveBound {
+    Lower(usize),
+    Upper(usize)
+}
+
+impl<T> Bounds<T> where T: Float {
+    pub fn new(lower: Tensor<T>, upper: Tensor<T>) -> Self {
+        assert_eq!(lower.data.len(), upper.data.len(), "Lower and upper bounds must have the same size");
+        assert!(lower.data.iter().zip(upper.data.iter()).all(|(l, u)| l <= u), "Lower bound must not exceed upper bound");
+        Self { lower, upper }
+    }
+
+    pub fn non_negative(dim: usize) -> Self {
+        Self::new(Vector::ket(vec![T::zero(); dim]), Vector::ket(vec![T::infinity(); dim]))
+    }
+
+    pub fn unit(dim: usize) -> Self {
+        Self::new(Vector::ket(vec![T::zero(); dim]), Vector::ket(vec![T::one(); dim]))
+    }
+
+    /// Closest point of the box, the shape of `arg` is kept
+    pub fn project(&self, arg: &Tensor<T>) -> Tensor<T> {
+        self.validate(arg);
+        let data = arg.data.iter()
+            .zip(self.lower.data.iter().zip(self.upper.data.iter()))
+            .map(|(&value, (&lower, &upper))| value.max(lower).min(upper))
+            .collect();
+        Tensor { shape: arg.shape.clone(), data }
+    }
+
+    /// x - P(x - ∇f), zero exactly at the points satisfying the first order conditions on the box
+    pub fn projected_gradient(&self, arg: &Tensor<T>, grad: &Tensor<T>) -> Tensor<T> {
+        arg - &self.project(&(arg - grad))
+    }
+
+    pub fn contains(&self, arg: &Tensor<T>) -> bool {
+        self.project(arg) == *arg
+    }
+
+    pub fn active(&self, arg: &Tensor<T>) -> Vec<ActiveBound> {
+        self.validate(arg);
+        arg.data.iter()
+            .enumerate()
+            .filter_map(|(index, &value)| {
+                if value <= self.lower.data[index] {
+                    Some(ActiveBound::Lower(index))
+                } else if value >= self.upper.data[index] {
+                    Some(ActiveBound::Upper(index))
+                } else {
+                    None
+                }
+            })
+            .collect()
+    }
+
+    /// Coordinates that may move along -∇f: the ones not held at a limit by the gradient
+    pub(crate) fn free(&self, arg: &Tensor<T>, grad: &Tensor<T>) -> Vec<bool> {
+        arg.data.iter()
+            .zip(grad.data.iter())
+            .enumerate()
+            .map(|(index, (&value, &slope))| {
+                !((value <= self.lower.data[index] && slope > T::zero()) || (value >= self.upper.data[index] && slope < T::zero()))
+            })
+            .collect()
+    }
+
+    fn validate(&self, arg: &Tensor<T>) {
+        assert_eq!(arg.data.len(), self.lower.data.len(), "Bounds size {} doesn't match the argument size {}", self.lower.data.len(), arg.data.len());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use tensor::Vector;
+    use super::{ActiveBound, Bounds};
+
+    #[test]
+    fn projection_and_active_bounds() {
+        let bounds = Bounds::new(Vector::ket(vec![0.0, -1.0, f64::NEG_INFINITY]), Vector::ket(vec![1.0, 1.0, 0.0]));
+        let projected = bounds.project(&Vector::bra(vec![2.0, 0.5, 3.0]));
+        assert_eq!(projected, Vector::bra(vec![1.0, 0.5, 0.0]));
+        assert!(bounds.contains(&projected));
+        assert_eq!(bounds.active(&projected), vec![ActiveBound::Upper(0), ActiveBound::Upper(2)]);
+
+        let grad = Vector::bra(vec![-2.0, 0.5, 1.0]);
+        assert_eq!(bounds.projected_gradient(&projected, &grad), Vector::bra(vec![0.0, 0.5, 1.0]));
+        assert_eq!(bounds.free(&projected, &grad), vec![false, true, true]);
+    }
+}
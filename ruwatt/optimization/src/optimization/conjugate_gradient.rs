@@ -0,0 +1,61 @@
+use num::Float;
+use std::iter::Sum;
+use tensor::Tensor;
+
+pub(crate) fn inner<T>(a: &Tensor<T>, b: &Tensor<T>) -> T where T: Float + Sum {
+    a.data.iter().zip(b.data.iter()).map(|(&x, &y)| x * y).sum()
+}
+
+/// Truncated conjugate gradient for A·x = b, where A is only available through `product(v) = A·v`.
+/// Stops after `max_iterations`, when |r| ≤ tolerance, or on non-positive curvature pᵀAp ≤ 0;
+/// in the last case the iterate so far is returned, or b itself on the first iteration.
+pub fn conjugate_gradient<T>(product: &dyn Fn(&Tensor<T>) -> Tensor<T>, b: &Tensor<T>, max_iterations: usize, tolerance: T) -> Tensor<T> where T: Float + Sum {
+    let mut x = b * T::zero();
+    let mut r = b.clone();
+    let mut p = b.clone();
+    let mut r_norm = inner(&r, &r);
+    for iteration in 0..max_iterations {
+        if r_norm.sqrt() <= tolerance {
+            break;
+        }
+        let ap = product(&p);
+        let curvature = inner(&p, &ap);
+        if curvature <= T::zero() {
+            return if iteration == 0 { b.clone() } else { x };
+        }
+        let alpha = r_norm / curvature;
+        x = &x + &(&p * alpha);
+        r = &r - &(&ap * alpha);
+        let r_norm_next = inner(&r, &r);
+        p = &r + &(&p * (r_norm_next / r_norm));
+        r_norm = r_norm_next;
+    }
+    x
+}
+
+#[cfg(test)]
+mod tests {
+    use tensor::{assert_near, dot, Matrix, Tensor, Vector};
+    use super::conjugate_gradient;
+
+    #[test]
+    fn spd_system() {
+        let a = Matrix::new(vec![
+            vec![4.0, 1.0, 0.0],
+            vec![1.0, 3.0, 1.0],
+            vec![0.0, 1.0, 2.0]
+        ]);
+        let x = Vector::ket(vec![1.0, -2.0, 0.5]);
+        let b = dot(&a, &x);
+        let recieved = conjugate_gradient(&|v: &Tensor| dot(&a, v), &b, 3, 1e-12);
+        assert_near!(recieved, x, 1e-10);
+    }
+
+    #[test]
+    fn negative_curvature() {
+        let a = Matrix::square(vec![-2.0, 0.0, 0.0, 1.0]);
+        let b = Vector::ket(vec![1.0, 1.0]);
+        let recieved = conjugate_gradient(&|v: &Tensor| dot(&a, v), &b, 10, 1e-12);
+        assert_eq!(recieved, b);
+    }
+}
@@ -0,0 +1,171 @@
+use num::Float;
+use std::{fmt::Debug, time::Instant};
+use tensor::Tensor;
+use super::{ObserverRef, OptimizationResult, Optimizer, Problem, Progress, RunSummary, StopCriteria, StopReason, StopState};
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum CoordinateMethod {
+    /// All 2n points ±size·eᵢ are evaluated and the best one is taken if it improves the value
+    Search,
+    /// Coordinates are tried one after another and every improvement is taken at once
+    Descent
+}
+
+/// Pattern search over the coordinate axes: points ±`step_size` along every axis are probed as `method` describes.
+/// With a fixed step the iterates stay on a grid of that spacing, `decrement_step` divides the step
+/// by the step number to refine below it. Observers get the grid point and no gradient information.
+#[derive(Clone)]
+pub struct CoordinateSearch<'a, T> where T: Float + Debug {
+    pub step_count: usize,
+    pub stop_criteria: StopCriteria<'a, T>,
+    pub method: CoordinateMethod,
+    pub step_size: T,
+    pub decrement_step: bool,
+    pub observers: Vec<ObserverRef<'a, T>>,
+    pub verbose: bool
+}
+
+impl<'a, T> Default for CoordinateSearch<'a, T> where T: Float + Debug {
+    fn default() -> Self {
+        Self {
+            step_count: 100,
+            stop_criteria: StopCriteria::default(),
+            method: CoordinateMethod::Search,
+            step_size: T::one(),
+            decrement_step: false,
+            observers: vec![],
+            verbose: false
+        }
+    }
+}
+
+impl<'a, T> Optimizer<T> for CoordinateSearch<'a, T> where T: Float + Debug {
+    fn minimize(&mut self, problem: &Problem<T>) -> OptimizationResult<T> {
+        self.log("Coordinate search started");
+        let start = Instant::now();
+
+        let mut arg = problem.start_point.clone();
+        let mut reason = StopReason::StepCount;
+        let mut steps = 0;
+        problem.reset_evaluations();
+        let mut value = problem.value(&arg);
+        let mut progress = Progress::start(&self.observers, &arg, value);
+        for step in 0..self.step_count {
+            let size = if self.decrement_step { self.step_size / T::from(step + 1).unwrap() } else { self.step_size };
+            let (next_arg, next_value) = match self.method {
+                CoordinateMethod::Search => search(problem, &arg, value, size),
+                CoordinateMethod::Descent => descent(problem, &arg, value, size)
+            };
+            let value_prev = value;
+            let arg_change = (&next_arg - &arg).length();
+            arg = next_arg;
+            value = next_value;
+            steps = step + 1;
+            if let Some(stop) = progress.step(steps, &arg, value, T::zero(), arg_change) {
+                reason = stop;
+                break;
+            }
+            let state = StopState {
+                step,
+                arg: &arg,
+                value,
+                value_prev,
+                arg_change,
+                evaluations: problem.evaluations(),
+                elapsed: start.elapsed()
+            };
+            if let Some(stop) = self.stop_criteria.check(&state) {
+                reason = stop;
+                break;
+            }
+        }
+        self.log(&format!("Coordinate search stopped by {:?} after {} steps", reason, steps));
+        self.log(&format!("Coordinate search elapsed in {:?}", start.elapsed()));
+        progress.finish(RunSummary { reason, steps, evaluations: problem.evaluations(), elapsed: start.elapsed() }, None)
+    }
+}
+
+impl<'a, T> CoordinateSearch<'a, T> where T: Float + Debug {
+    fn log(&self, message: &str) {
+        if self.verbose {
+            println!("{}", message);
+        }
+    }
+}
+
+fn displaced<T>(arg: &Tensor<T>, index: usize, delta: T) -> Tensor<T> where T: Float {
+    let mut point = arg.clone();
+    point.data[index] = point.data[index] + delta;
+    point
+}
+
+fn search<T>(problem: &Problem<T>, arg: &Tensor<T>, value: T, size: T) -> (Tensor<T>, T) where T: Float {
+    (0..arg.data.len())
+        .flat_map(|index| [displaced(arg, index, size), displaced(arg, index, -size)])
+        .fold((arg.clone(), value), |best, point| {
+            let point_value = problem.value(&point);
+            if point_value < best.1 { (point, point_value) } else { best }
+        })
+}
+
+fn descent<T>(problem: &Problem<T>, arg: &Tensor<T>, value: T, size: T) -> (Tensor<T>, T) where T: Float {
+    let mut best = (arg.clone(), value);
+    for index in 0..arg.data.len() {
+        for delta in [size, -size] {
+            let point = displaced(&best.0, index, delta);
+            let point_value = problem.value(&point);
+            if point_value < best.1 {
+                best = (point, point_value);
+                break;
+            }
+        }
+    }
+    best
+}
+
+#[cfg(test)]
+mod tests {
+    use tensor::{Tensor, Vector, assert_near};
+    use super::{CoordinateMethod, CoordinateSearch, Optimizer, Problem};
+
+    fn f(x: &Tensor) -> f64 {
+        x.get_v(0).powi(2) + x.get_v(1).powi(2) + 2.0
+    }
+
+    fn g(x: &Tensor) -> f64 {
+        0.26 * (x.get_v(0).powi(2) + x.get_v(1).powi(2)) - 0.48 * x.get_v(0) * x.get_v(1)
+    }
+
+    #[test]
+    fn coordinate_search_quadratic() {
+        let problem = Problem::new(f, Vector::ket(vec![3.0, 4.0]));
+        let mut optimizator = CoordinateSearch {
+            step_count: 7,
+            ..Default::default()
+        };
+        let result = optimizator.minimize(&problem);
+        assert_eq!(result.arg, Vector::ket(vec![0.0, 0.0]));
+        assert_eq!(result.value, 2.0);
+    }
+
+    #[test]
+    fn coordinate_search_decrement() {
+        let problem = Problem::new(g, Vector::ket(vec![2.0, 2.0]));
+        let mut optimizator = CoordinateSearch {
+            step_count: 50,
+            decrement_step: true,
+            ..Default::default()
+        };
+        let result = optimizator.minimize(&problem);
+        assert_near!(result.arg, Vector::ket(vec![0.6768566, 0.6572714]), 1e-6);
+    }
+
+    #[test]
+    fn coordinate_descent_beats_search() {
+        let problem = Problem::new(g, Vector::ket(vec![2.0, 2.0]));
+        let run = |method| CoordinateSearch { method, step_count: 50, decrement_step: true, ..Default::default() }.minimize(&problem);
+        let search = run(CoordinateMethod::Search);
+        let descent = run(CoordinateMethod::Descent);
+        assert!(descent.value < search.value, "{} {}", descent.value, search.value);
+    }
+}
@@ -2,13 +2,73 @@ use std::fmt::Debug;
 
 use num::Float;
 use tensor::{Tensor, Vector, assert_vector};
+use super::{Dual, HyperDual};
 
-fn derivative<T>(f: &dyn Fn(&Tensor<T>) -> T, index: usize, point: &Tensor<T>, delta: T) -> T where T: Float {
-    let dw = Vector::ort(point.is_bra(), point.dim(), index, delta);
-    (f(&(point + &dw)) - f(point)) / delta
+/// Finite difference formula used for numeric derivatives.
+/// Truncation error for step h: Forward O(h), Central O(h²), FivePoint O(h⁴),
+/// Richardson O(h⁸) (central differences at h, h/2, h/4, h/8 extrapolated to zero step).
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum DifferenceScheme {
+    #[default]
+    Forward,
+    Central,
+    FivePoint,
+    Richardson
+}
+
+const RICHARDSON_LEVELS: usize = 4;
+
+/// Step for the `index` coordinate: `delta` scaled to |x| so that large coordinates
+/// don't lose the increment to rounding, rounded so that x + h - x == h exactly.
+fn step<T>(point: &Tensor<T>, index: usize, delta: T) -> T where T: Float {
+    let x = point.data[index];
+    let h = delta * x.abs().max(T::one());
+    (x + h) - x
+}
+
+fn shifted<T>(point: &Tensor<T>, index: usize, offset: T) -> Tensor<T> where T: Float {
+    let mut result = point.clone();
+    result.data[index] = result.data[index] + offset;
+    result
+}
+
+fn derivative<T>(f: &dyn Fn(&Tensor<T>) -> T, index: usize, point: &Tensor<T>, delta: T, scheme: DifferenceScheme) -> T where T: Float {
+    let h = step(point, index, delta);
+    let at = |offset: T| f(&shifted(point, index, offset));
+    let central = |h: T| (at(h) - at(-h)) / (h + h);
+    let two = T::from(2).unwrap();
+    match scheme {
+        DifferenceScheme::Forward => (at(h) - f(point)) / h,
+        DifferenceScheme::Central => central(h),
+        DifferenceScheme::FivePoint => {
+            let eight = T::from(8).unwrap();
+            (at(-two * h) - eight * at(-h) + eight * at(h) - at(two * h)) / (T::from(12).unwrap() * h)
+        },
+        DifferenceScheme::Richardson => {
+            let mut table: Vec<T> = Vec::with_capacity(RICHARDSON_LEVELS);
+            let mut h = h;
+            for level in 0..RICHARDSON_LEVELS {
+                let mut estimate = central(h);
+                let mut factor = T::one();
+                for previous in table.iter_mut().take(level) {
+                    factor = factor * T::from(4).unwrap();
+                    let improved = (factor * estimate - *previous) / (factor - T::one());
+                    *previous = estimate;
+                    estimate = improved;
+                }
+                table.push(estimate);
+                h = h / two;
+            }
+            table[RICHARDSON_LEVELS - 1]
+        }
+    }
 }
 
 pub fn gradient<T>(f: &dyn Fn(&Tensor<T>) -> T, point: &Tensor<T>, delta: T) -> Tensor<T> where T: Float {
+    gradient_with(f, point, delta, DifferenceScheme::Forward)
+}
+
+pub fn gradient_with<T>(f: &dyn Fn(&Tensor<T>) -> T, point: &Tensor<T>, delta: T, scheme: DifferenceScheme) -> Tensor<T> where T: Float {
     assert_vector!(point);
     let dim = point.dim();
     let mut result = if point.is_bra() {
@@ -17,39 +77,102 @@ pub fn gradient<T>(f: &dyn Fn(&Tensor<T>) -> T, point: &Tensor<T>, delta: T) ->
         Vector::<T>::ket(vec![T::zero(); dim])
     };
     for i in 0..dim {
-        let value = derivative(f, i, point, delta);
+        let value = derivative(f, i, point, delta, scheme);
         result.set_v(i, value);
     }
     result
 }
 
-pub fn hessian<T>(f: &dyn Fn(&Tensor<T>) -> T, point: &Tensor<T>, delta: T) -> Tensor<T> where T: Float + Debug{
+pub fn hessian<T>(f: &dyn Fn(&Tensor<T>) -> T, point: &Tensor<T>, delta: T) -> Tensor<T> where T: Float + Debug {
+    hessian_with(f, point, delta, DifferenceScheme::Forward)
+}
+
+/// ∂²f/∂xᵢ∂xⱼ as the `scheme` derivative along j of the `scheme` derivative along i.
+/// For Forward this is the (f(x+hᵢ+hⱼ) - f(x+hᵢ) - f(x+hⱼ) + f(x)) / hᵢhⱼ formula.
+pub fn hessian_with<T>(f: &dyn Fn(&Tensor<T>) -> T, point: &Tensor<T>, delta: T, scheme: DifferenceScheme) -> Tensor<T> where T: Float + Debug {
+    assert_vector!(point);
+    let size = point.dim();
+    let mut result = Tensor::<T>::zeros(vec![size, size]);
+    for i in 0..size {
+        let partial = |x: &Tensor<T>| derivative(f, i, x, delta, scheme);
+        for j in i..size {
+            let value = derivative(&partial, j, point, delta, scheme);
+            result.set(vec![i, j], value);
+            result.set(vec![j, i], value);
+        }
+    }
+    result
+}
+
+/// H·v without building H: (∇f(x + εv) - ∇f(x)) / ε, ε = delta·max(1, |x|) / |v|
+pub fn hessian_vector_product<T>(grad: &dyn Fn(&Tensor<T>) -> Tensor<T>, point: &Tensor<T>, vector: &Tensor<T>, delta: T) -> Tensor<T> where T: Float {
+    let length = vector.length();
+    if length == T::zero() {
+        return vector.clone();
+    }
+    let epsilon = delta * point.length().max(T::one()) / length;
+    let shifted = point + &(vector * epsilon);
+    &(&grad(&shifted) - &grad(point)) / epsilon
+}
+
+/// Maximum over coordinates of |analytic - numeric| / max(|analytic|, |numeric|, 1),
+/// numeric gradient is taken with Richardson extrapolation.
+pub fn check_gradient<T>(f: &dyn Fn(&Tensor<T>) -> T, grad: &dyn Fn(&Tensor<T>) -> Tensor<T>, point: &Tensor<T>) -> T where T: Float {
+    let analytic = grad(point);
+    let numeric = gradient_with(f, point, T::from(1e-3).unwrap(), DifferenceScheme::Richardson);
+    assert_eq!(analytic.data.len(), numeric.data.len(), "Gradient size {} doesn't match point size {}", analytic.data.len(), numeric.data.len());
+    analytic.data.iter()
+        .zip(numeric.data.iter())
+        .map(|(&a, &n)| (a - n).abs() / a.abs().max(n.abs()).max(T::one()))
+        .fold(T::zero(), T::max)
+}
+
+/// Exact gradient via forward-mode dual numbers: one evaluation of `f` per coordinate.
+/// `f` is usually a generic cost function instantiated with `Dual<T>`, e.g. `&cost::<Dual<f64>>`.
+pub fn gradient_exact<T>(f: &dyn Fn(&Tensor<Dual<T>>) -> Dual<T>, point: &Tensor<T>) -> Tensor<T> where T: Float {
+    assert_vector!(point);
+    let mut arg = Dual::constants(point);
+    let data = (0..point.data.len())
+        .map(|i| {
+            arg.data[i].dual = T::one();
+            let value = f(&arg).dual;
+            arg.data[i].dual = T::zero();
+            value
+        })
+        .collect();
+    Tensor { shape: point.shape.clone(), data }
+}
+
+/// Exact hessian via nested dual numbers `Dual<Dual<T>>`: the inner part seeds the i-th
+/// coordinate, the outer part the j-th one, so `dual.dual` of the result is ∂²f/∂xᵢ∂xⱼ.
+pub fn hessian_exact<T>(f: &dyn Fn(&Tensor<HyperDual<T>>) -> HyperDual<T>, point: &Tensor<T>) -> Tensor<T> where T: Float {
     assert_vector!(point);
     let size = point.dim();
-    let is_bra = point.is_bra();
+    let mut arg = Tensor {
+        shape: point.shape.clone(),
+        data: point.data.iter().map(|&value| Dual::constant(Dual::constant(value))).collect()
+    };
     let mut result = Tensor::<T>::zeros(vec![size, size]);
     for i in 0..size {
-        for j in 0..size {
-            let value = if i == j {
-                let _2 = T::from(2).unwrap();
-                let dw = Vector::ort(is_bra, size, i, delta);
-                f(&(point + &(&dw * _2))) - _2 * f(&(point + &dw)) + f(point)
-            } else {
-                let dw_i = Vector::ort(is_bra, size, i, delta);
-                let dw_j = Vector::ort(is_bra, size, j, delta);
-                f(&(point + &(&dw_i + &dw_j))) - f(&(point + &dw_i)) - f(&(point + &dw_j)) + f(point)
-            };
-            result.set(vec![i, j], value / (delta * delta));
+        arg.data[i].real.dual = T::one();
+        for j in i..size {
+            arg.data[j].dual.real = T::one();
+            let value = f(&arg).dual.dual;
+            arg.data[j].dual.real = T::zero();
+            result.set(vec![i, j], value);
+            result.set(vec![j, i], value);
         }
+        arg.data[i].real.dual = T::zero();
     }
     result
 }
 
 #[cfg(test)]
 mod tests {
-    use num::abs;
-    use tensor::{Tensor, Vector, Matrix, assert_near};
-    use super::{ derivative, gradient, hessian };
+    use num::{abs, Float};
+    use tensor::{dot, Tensor, Vector, Matrix, assert_near};
+    use super::{ derivative, gradient, hessian, hessian_with, gradient_exact, hessian_exact, check_gradient, hessian_vector_product, DifferenceScheme };
+    use super::super::{Dual, HyperDual};
 
     fn f(x: &Tensor) -> f64 {
         x.get_v(0).powi(2) + x.get_v(1).powi(2)
@@ -58,10 +181,51 @@ mod tests {
     #[test]
     fn test_derivative() {
         let vector = Vector::ket(vec![1.0, 1.0]);
-        let recieved = derivative(&f, 0, &vector, 0.0001);
+        let recieved = derivative(&f, 0, &vector, 0.0001, DifferenceScheme::Forward);
         assert!(abs(recieved - 2.0) < 0.001)
     }
 
+    #[test]
+    fn test_difference_schemes() {
+        let f = |x: &Tensor| x.get_v(0).exp() * x.get_v(1).sin();
+        let point = Vector::ket(vec![0.5, 100.0]);
+        let expected = 0.5.exp() * 100.0.cos();
+        let error = |scheme, delta| abs(derivative(&f, 1, &point, delta, scheme) - expected);
+        assert!(error(DifferenceScheme::Forward, 1e-7) < 1e-5);
+        assert!(error(DifferenceScheme::Central, 1e-5) < 1e-6);
+        assert!(error(DifferenceScheme::FivePoint, 1e-4) < 1e-8);
+        assert!(error(DifferenceScheme::Richardson, 1e-3) < 1e-11);
+        assert!(error(DifferenceScheme::Central, 1e-3) > error(DifferenceScheme::Richardson, 1e-3));
+    }
+
+    #[test]
+    fn test_hessian_schemes() {
+        let point = Vector::ket(vec![-1.5, 2.0]);
+        let expected = hessian_exact(&rosenbrock::<HyperDual<f64>>, &point);
+        assert_near!(expected, hessian_with(&rosenbrock::<f64>, &point, 1e-4, DifferenceScheme::Central), 1e-3);
+        assert_near!(expected, hessian_with(&rosenbrock::<f64>, &point, 1e-3, DifferenceScheme::FivePoint), 1e-5);
+        assert_near!(expected, hessian_with(&rosenbrock::<f64>, &point, 1e-2, DifferenceScheme::Richardson), 1e-6);
+    }
+
+    #[test]
+    fn test_hessian_vector_product() {
+        let point = Vector::ket(vec![-1.5, 2.0]);
+        let vector = Vector::ket(vec![0.3, -1.0]);
+        let grad = |x: &Tensor| gradient_exact(&rosenbrock::<Dual<f64>>, x);
+        let recieved = hessian_vector_product(&grad, &point, &vector, 1e-7);
+        let expected = dot(&hessian_exact(&rosenbrock::<HyperDual<f64>>, &point), &vector);
+        assert_near!(expected, recieved, 1e-3)
+    }
+
+    #[test]
+    fn test_check_gradient() {
+        let point = Vector::bra(vec![-1.5, 2.0]);
+        let exact = |x: &Tensor| gradient_exact(&rosenbrock::<Dual<f64>>, x);
+        assert!(check_gradient(&rosenbrock::<f64>, &exact, &point) < 1e-8);
+        let wrong = |x: &Tensor| &gradient_exact(&rosenbrock::<Dual<f64>>, x) * 1.01;
+        assert!(check_gradient(&rosenbrock::<f64>, &wrong, &point) > 1e-3);
+    }
+
     #[test]
     fn test_gradient() {
         let vector = Vector::ket(vec![1.0, 1.0]);
@@ -80,4 +244,29 @@ mod tests {
         ]);
         assert_near!(expected, recieved, 0.001)
     }
+
+    fn rosenbrock<T: Float>(x: &Tensor<T>) -> T {
+        let (a, b) = (T::one(), T::from(100).unwrap());
+        (a - x.get_v(0)).powi(2) + b * (x.get_v(1) - x.get_v(0).powi(2)).powi(2)
+    }
+
+    #[test]
+    fn test_gradient_exact() {
+        let point = Vector::bra(vec![-1.5, 2.0]);
+        let recieved = gradient_exact(&rosenbrock::<Dual<f64>>, &point);
+        let expected = Vector::bra(vec![-2.0 * (1.0 - -1.5) - 400.0 * -1.5 * (2.0 - 2.25), 200.0 * (2.0 - 2.25)]);
+        assert_eq!(expected, recieved)
+    }
+
+    #[test]
+    fn test_hessian_exact() {
+        let point = Vector::ket(vec![-1.5, 2.0]);
+        let recieved = hessian_exact(&rosenbrock::<Dual<Dual<f64>>>, &point);
+        let expected = Matrix::new(vec![
+            vec![1200.0 * 2.25 - 400.0 * 2.0 + 2.0, 400.0 * 1.5],
+            vec![400.0 * 1.5, 200.0],
+        ]);
+        assert_near!(expected, recieved, 1e-12);
+        assert_near!(hessian(&rosenbrock::<f64>, &point, 1e-5), recieved, 0.1)
+    }
 }
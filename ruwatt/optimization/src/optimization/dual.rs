@@ -0,0 +1,418 @@
+use num::{Float, Num, NumCast, One, ToPrimitive, Zero};
+use std::cmp::Ordering;
+use std::fmt;
+use std::iter::{Product, Sum};
+use std::num::FpCategory;
+use std::ops;
+use tensor::Tensor;
+
+/// Forward-mode dual number `real + dual·ε`, ε² = 0.
+/// Evaluating a generic `T: Float` function on `Dual { real: x, dual: 1 }` yields f(x) and f'(x).
+/// Comparisons look at the real part only, so branches behave as for plain numbers.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Dual<T> where T: Float {
+    pub real: T,
+    pub dual: T
+}
+
+/// Nested dual number carrying second derivatives
+pub type HyperDual<T> = Dual<Dual<T>>;
+
+impl<T> Dual<T> where T: Float {
+    pub fn new(real: T, dual: T) -> Self {
+        Self { real, dual }
+    }
+
+    pub fn constant(real: T) -> Self {
+        Self { real, dual: T::zero() }
+    }
+
+    pub fn variable(real: T) -> Self {
+        Self { real, dual: T::one() }
+    }
+
+    /// Lifts every element of a tensor to a constant
+    pub fn constants(tensor: &Tensor<T>) -> Tensor<Self> {
+        Tensor { shape: tensor.shape.clone(), data: tensor.data.iter().map(|&value| Self::constant(value)).collect() }
+    }
+
+    /// f(real) with derivative f'(real)·dual
+    fn chain(&self, value: T, derivative: T) -> Self {
+        Self { real: value, dual: derivative * self.dual }
+    }
+}
+
+impl<T> PartialEq for Dual<T> where T: Float {
+    fn eq(&self, other: &Self) -> bool {
+        self.real == other.real
+    }
+}
+
+impl<T> PartialOrd for Dual<T> where T: Float {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        self.real.partial_cmp(&other.real)
+    }
+}
+
+impl<T> fmt::Display for Dual<T> where T: Float + fmt::Display {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} + {}ε", self.real, self.dual)
+    }
+}
+
+impl<T> ops::Add for Dual<T> where T: Float {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        Self::new(self.real + other.real, self.dual + other.dual)
+    }
+}
+
+impl<T> ops::Sub for Dual<T> where T: Float {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        Self::new(self.real - other.real, self.dual - other.dual)
+    }
+}
+
+impl<T> ops::Mul for Dual<T> where T: Float {
+    type Output = Self;
+
+    fn mul(self, other: Self) -> Self {
+        Self::new(self.real * other.real, self.real * other.dual + self.dual * other.real)
+    }
+}
+
+impl<T> ops::Div for Dual<T> where T: Float {
+    type Output = Self;
+
+    fn div(self, other: Self) -> Self {
+        let real = self.real / other.real;
+        Self::new(real, (self.dual - real * other.dual) / other.real)
+    }
+}
+
+impl<T> ops::Rem for Dual<T> where T: Float {
+    type Output = Self;
+
+    fn rem(self, other: Self) -> Self {
+        let quotient = (self.real / other.real).trunc();
+        Self::new(self.real % other.real, self.dual - quotient * other.dual)
+    }
+}
+
+impl<T> ops::Neg for Dual<T> where T: Float {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self::new(-self.real, -self.dual)
+    }
+}
+
+impl<T> ops::AddAssign for Dual<T> where T: Float {
+    fn add_assign(&mut self, other: Self) {
+        *self = *self + other;
+    }
+}
+
+impl<T> ops::SubAssign for Dual<T> where T: Float {
+    fn sub_assign(&mut self, other: Self) {
+        *self = *self - other;
+    }
+}
+
+impl<T> ops::MulAssign for Dual<T> where T: Float {
+    fn mul_assign(&mut self, other: Self) {
+        *self = *self * other;
+    }
+}
+
+impl<T> ops::DivAssign for Dual<T> where T: Float {
+    fn div_assign(&mut self, other: Self) {
+        *self = *self / other;
+    }
+}
+
+impl<T> Sum for Dual<T> where T: Float {
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::zero(), |sum, value| sum + value)
+    }
+}
+
+impl<'a, T> Sum<&'a Dual<T>> for Dual<T> where T: Float {
+    fn sum<I: Iterator<Item = &'a Self>>(iter: I) -> Self {
+        iter.fold(Self::zero(), |sum, &value| sum + value)
+    }
+}
+
+impl<T> Product for Dual<T> where T: Float {
+    fn product<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::one(), |product, value| product * value)
+    }
+}
+
+impl<T> Zero for Dual<T> where T: Float {
+    fn zero() -> Self {
+        Self::constant(T::zero())
+    }
+
+    fn is_zero(&self) -> bool {
+        self.real.is_zero()
+    }
+}
+
+impl<T> One for Dual<T> where T: Float {
+    fn one() -> Self {
+        Self::constant(T::one())
+    }
+}
+
+impl<T> Num for Dual<T> where T: Float {
+    type FromStrRadixErr = T::FromStrRadixErr;
+
+    fn from_str_radix(text: &str, radix: u32) -> Result<Self, Self::FromStrRadixErr> {
+        T::from_str_radix(text, radix).map(Self::constant)
+    }
+}
+
+impl<T> ToPrimitive for Dual<T> where T: Float {
+    fn to_i64(&self) -> Option<i64> {
+        self.real.to_i64()
+    }
+
+    fn to_u64(&self) -> Option<u64> {
+        self.real.to_u64()
+    }
+
+    fn to_f64(&self) -> Option<f64> {
+        self.real.to_f64()
+    }
+}
+
+impl<T> NumCast for Dual<T> where T: Float {
+    fn from<N: ToPrimitive>(n: N) -> Option<Self> {
+        <T as NumCast>::from(n).map(Self::constant)
+    }
+}
+
+impl<T> Float for Dual<T> where T: Float {
+    fn nan() -> Self { Self::constant(T::nan()) }
+    fn infinity() -> Self { Self::constant(T::infinity()) }
+    fn neg_infinity() -> Self { Self::constant(T::neg_infinity()) }
+    fn neg_zero() -> Self { Self::constant(T::neg_zero()) }
+    fn min_value() -> Self { Self::constant(T::min_value()) }
+    fn min_positive_value() -> Self { Self::constant(T::min_positive_value()) }
+    fn epsilon() -> Self { Self::constant(T::epsilon()) }
+    fn max_value() -> Self { Self::constant(T::max_value()) }
+
+    fn is_nan(self) -> bool { self.real.is_nan() || self.dual.is_nan() }
+    fn is_infinite(self) -> bool { self.real.is_infinite() || self.dual.is_infinite() }
+    fn is_finite(self) -> bool { self.real.is_finite() && self.dual.is_finite() }
+    fn is_normal(self) -> bool { self.real.is_normal() }
+    fn classify(self) -> FpCategory { self.real.classify() }
+    fn is_sign_positive(self) -> bool { self.real.is_sign_positive() }
+    fn is_sign_negative(self) -> bool { self.real.is_sign_negative() }
+    fn integer_decode(self) -> (u64, i16, i8) { self.real.integer_decode() }
+
+    fn floor(self) -> Self { Self::constant(self.real.floor()) }
+    fn ceil(self) -> Self { Self::constant(self.real.ceil()) }
+    fn round(self) -> Self { Self::constant(self.real.round()) }
+    fn trunc(self) -> Self { Self::constant(self.real.trunc()) }
+    fn fract(self) -> Self { Self::new(self.real.fract(), self.dual) }
+    fn signum(self) -> Self { Self::constant(self.real.signum()) }
+
+    fn abs(self) -> Self {
+        self.chain(self.real.abs(), self.real.signum())
+    }
+
+    fn mul_add(self, a: Self, b: Self) -> Self {
+        self * a + b
+    }
+
+    fn recip(self) -> Self {
+        self.chain(self.real.recip(), -self.real.powi(2).recip())
+    }
+
+    fn powi(self, n: i32) -> Self {
+        let n_t = <T as NumCast>::from(n).unwrap();
+        self.chain(self.real.powi(n), n_t * self.real.powi(n - 1))
+    }
+
+    fn powf(self, n: Self) -> Self {
+        let real = self.real.powf(n.real);
+        let base = n.real * self.real.powf(n.real - T::one()) * self.dual;
+        let exponent = if n.dual.is_zero() { T::zero() } else { real * self.real.ln() * n.dual };
+        Self::new(real, base + exponent)
+    }
+
+    fn sqrt(self) -> Self {
+        let real = self.real.sqrt();
+        self.chain(real, (real + real).recip())
+    }
+
+    fn exp(self) -> Self {
+        let real = self.real.exp();
+        self.chain(real, real)
+    }
+
+    fn exp2(self) -> Self {
+        let real = self.real.exp2();
+        self.chain(real, real * T::from(2).unwrap().ln())
+    }
+
+    fn ln(self) -> Self {
+        self.chain(self.real.ln(), self.real.recip())
+    }
+
+    fn log(self, base: Self) -> Self {
+        self.ln() / base.ln()
+    }
+
+    fn log2(self) -> Self {
+        self.chain(self.real.log2(), (self.real * T::from(2).unwrap().ln()).recip())
+    }
+
+    fn log10(self) -> Self {
+        self.chain(self.real.log10(), (self.real * T::from(10).unwrap().ln()).recip())
+    }
+
+    fn max(self, other: Self) -> Self {
+        if other.real > self.real || self.real.is_nan() { other } else { self }
+    }
+
+    fn min(self, other: Self) -> Self {
+        if other.real < self.real || self.real.is_nan() { other } else { self }
+    }
+
+    #[allow(deprecated)]
+    fn abs_sub(self, other: Self) -> Self {
+        (self - other).max(Self::zero())
+    }
+
+    fn cbrt(self) -> Self {
+        let real = self.real.cbrt();
+        self.chain(real, (<T as NumCast>::from(3).unwrap() * real * real).recip())
+    }
+
+    fn hypot(self, other: Self) -> Self {
+        (self * self + other * other).sqrt()
+    }
+
+    fn sin(self) -> Self {
+        self.chain(self.real.sin(), self.real.cos())
+    }
+
+    fn cos(self) -> Self {
+        self.chain(self.real.cos(), -self.real.sin())
+    }
+
+    fn tan(self) -> Self {
+        let real = self.real.tan();
+        self.chain(real, T::one() + real * real)
+    }
+
+    fn asin(self) -> Self {
+        self.chain(self.real.asin(), (T::one() - self.real * self.real).sqrt().recip())
+    }
+
+    fn acos(self) -> Self {
+        self.chain(self.real.acos(), -(T::one() - self.real * self.real).sqrt().recip())
+    }
+
+    fn atan(self) -> Self {
+        self.chain(self.real.atan(), (T::one() + self.real * self.real).recip())
+    }
+
+    fn atan2(self, other: Self) -> Self {
+        let denominator = self.real * self.real + other.real * other.real;
+        Self::new(
+            self.real.atan2(other.real),
+            (other.real * self.dual - self.real * other.dual) / denominator
+        )
+    }
+
+    fn sin_cos(self) -> (Self, Self) {
+        (self.sin(), self.cos())
+    }
+
+    fn exp_m1(self) -> Self {
+        self.chain(self.real.exp_m1(), self.real.exp())
+    }
+
+    fn ln_1p(self) -> Self {
+        self.chain(self.real.ln_1p(), (T::one() + self.real).recip())
+    }
+
+    fn sinh(self) -> Self {
+        self.chain(self.real.sinh(), self.real.cosh())
+    }
+
+    fn cosh(self) -> Self {
+        self.chain(self.real.cosh(), self.real.sinh())
+    }
+
+    fn tanh(self) -> Self {
+        let real = self.real.tanh();
+        self.chain(real, T::one() - real * real)
+    }
+
+    fn asinh(self) -> Self {
+        self.chain(self.real.asinh(), (self.real * self.real + T::one()).sqrt().recip())
+    }
+
+    fn acosh(self) -> Self {
+        self.chain(self.real.acosh(), (self.real * self.real - T::one()).sqrt().recip())
+    }
+
+    fn atanh(self) -> Self {
+        self.chain(self.real.atanh(), (T::one() - self.real * self.real).recip())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use num::Float;
+    use super::Dual;
+
+    fn derivative(f: impl Fn(Dual<f64>) -> Dual<f64>, x: f64) -> f64 {
+        f(Dual::variable(x)).dual
+    }
+
+    #[test]
+    fn arithmetic() {
+        let x = Dual::variable(3.0);
+        let c = Dual::constant(2.0);
+        assert_eq!((x * x + c * x).dual, 8.0);
+        assert_eq!((c / x).dual, -2.0 / 9.0);
+        assert_eq!((x - c).real, 1.0);
+    }
+
+    #[test]
+    fn elementary_functions() {
+        let x = 0.7;
+        assert!((derivative(|v| v.sin(), x) - x.cos()).abs() < 1e-15);
+        assert!((derivative(|v| v.exp(), x) - x.exp()).abs() < 1e-15);
+        assert!((derivative(|v| v.ln(), x) - 1.0 / x).abs() < 1e-15);
+        assert!((derivative(|v| v.sqrt(), x) - 0.5 / x.sqrt()).abs() < 1e-15);
+        assert!((derivative(|v| v.tanh(), x) - (1.0 - x.tanh().powi(2))).abs() < 1e-15);
+        assert!((derivative(|v| v.powf(Dual::constant(2.5)), x) - 2.5 * x.powf(1.5)).abs() < 1e-15);
+        assert!((derivative(|v| v.atan2(Dual::constant(2.0)), x) - 2.0 / (x * x + 4.0)).abs() < 1e-15);
+    }
+
+    #[test]
+    fn second_derivative() {
+        let x = Dual::new(Dual::variable(2.0), Dual::constant(1.0));
+        let y = x.powi(3);
+        assert_eq!(y.real.real, 8.0);
+        assert_eq!(y.dual.real, 12.0);
+        assert_eq!(y.dual.dual, 12.0);
+    }
+
+    #[test]
+    fn comparison_uses_real_part() {
+        assert!(Dual::new(1.0, 5.0) < Dual::new(2.0, 0.0));
+        assert_eq!(Dual::new(1.0, 5.0), Dual::constant(1.0));
+        assert_eq!(Dual::new(-3.0, 1.0).abs().dual, -1.0);
+    }
+}
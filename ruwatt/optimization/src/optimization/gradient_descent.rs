@@ -1,8 +1,9 @@
-use num::{Float, ToPrimitive};
-use indicatif::ProgressBar;
+use num::Float;
 use std::{fmt::Debug, iter::Sum, time::Instant};
 use tensor::{dot, Tensor, Vector};
-use super::{gradient, hessian, ResultEntry, ResultLogs};
+use super::{backtracking, conjugate_gradient, hessian_vector_product, wolfe};
+use super::{Bounds, DifferenceScheme, LineSearchResult, ObserverRef, Progress, UpdateRule, UpdateState};
+use super::{OptimizationResult, Optimizer, Problem, RunSummary, StopCriteria, StopReason, StopState};
 
 #[derive(Clone)]
 pub enum GradientDescentRegularization<T> where T: Float {
@@ -11,20 +12,20 @@ pub enum GradientDescentRegularization<T> where T: Float {
 
 #[derive(Clone)]
 pub struct GradientDescent<'a, T> where T: Float + Debug {
-    pub func: &'a dyn Fn(&Tensor<T>) -> T,
-    pub gradient: Option<&'a dyn Fn(&Tensor<T>) -> Tensor<T>>,
-    pub hessian: Option<&'a dyn Fn(&Tensor<T>) -> Tensor<T>>,
-    pub start_point: Tensor<T>, 
-    pub step_count: i16,
+    pub step_count: usize,
+    pub stop_criteria: StopCriteria<'a, T>,
     pub betta: T, 
     pub step_size: StepSize<T>,
-    pub save_progress: bool,
     pub derivative_delta: T,
-    pub results: ResultLogs<T>,
-    pub result: Option<ResultEntry<T>>,
+    pub difference_scheme: DifferenceScheme,
+    pub observers: Vec<ObserverRef<'a, T>>,
     pub grad_prev: Tensor<T>,
+    pub update_rule: UpdateRule<T>,
+    pub update_state: UpdateState<T>,
     pub verbose: bool,
-    pub regularization: Option<GradientDescentRegularization<T>>
+    pub regularization: Option<GradientDescentRegularization<T>>,
+    /// Every iterate is projected onto the box, the gradient norm criterion uses the projected gradient
+    pub bounds: Option<Bounds<T>>
 }
 
 #[derive(Clone, PartialEq)]
@@ -32,57 +33,109 @@ pub enum StepSize<T> where T: Float {
     OriginGrad,
     Fixed(T),
     Decrement(T),
-    Newton
+    Newton,
+    /// Newton step from truncated conjugate gradient with at most the given number of iterations,
+    /// the Hessian is only used through Hessian-vector products of the gradient
+    NewtonCG(usize),
+    /// Armijo backtracking along the gradient: α starts at 1 and is multiplied by `rho`
+    /// until f decreases by at least `c`·α·|∇f|²
+    Backtracking { c: T, rho: T },
+    /// Strong Wolfe line search along the gradient with sufficient decrease `c1` and curvature `c2`
+    Wolfe { c1: T, c2: T }
 }
 
 impl<'a, T> Default for GradientDescent<'a, T> where T: Float + Debug {
     fn default() -> Self {
         Self {
-            func: &|_| T::zero(),
-            gradient: None,
-            hessian: None,
-            start_point: Vector::ket(vec![T::zero()]),
             step_count: 1000,
+            stop_criteria: StopCriteria::default(),
             betta: T::from(0.7).unwrap(), 
             step_size: StepSize::Decrement(T::one()), 
-            save_progress: false,
             derivative_delta: T::from(0.0001).unwrap(),
-            results: ResultLogs::new(),
-            result: None,
+            difference_scheme: DifferenceScheme::Forward,
+            observers: vec![],
             grad_prev: Vector::ket(vec![T::zero()]),
+            update_rule: UpdateRule::Momentum,
+            update_state: UpdateState::new(),
             verbose: false,
-            regularization: None
+            regularization: None,
+            bounds: None
         }
     }
 }
 
-impl<'a, T> GradientDescent<'a, T> where T: Float + Sum + Debug {
-    pub fn run(&mut self) {
+impl<'a, T> Optimizer<T> for GradientDescent<'a, T> where T: Float + Sum + Send + Sync + Debug {
+    fn minimize(&mut self, problem: &Problem<T>) -> OptimizationResult<T> {
         self.log("Gradient descent started");
-        let bar = self.create_progress_bar();
         let start = Instant::now();
 
-        let mut arg = self.start_point.clone();
-        self.save_result((self.func)(&arg), arg.clone());
+        let mut arg = self.project(problem.start_point.clone());
+        let mut reason = StopReason::StepCount;
+        let mut steps = 0;
+        self.update_state.reset();
+        problem.reset_evaluations();
+        let mut value = problem.value(&arg);
+        let observers = self.observers.clone();
+        let mut progress = Progress::start(&observers, &arg, value);
         for step in 0..self.step_count {
-            self.inc_progressbar(&bar);
-            let grad = match self.gradient {
-                Some(grad_func) => grad_func(&arg),
-                None => gradient(self.func, &arg, self.derivative_delta)
+            let grad = self.gradient_at(problem, &self.update_rule.lookahead(&self.update_state, &arg));
+            if let Some(stop) = self.stop_criteria.check_gradient(&self.projected_gradient(&arg, &grad)) {
+                reason = stop;
+                break;
+            }
+            let grad_norm = grad.length();
+            let Some(grad) = self.set_grad_length(problem, grad, step, &arg) else {
+                reason = StopReason::LineSearch;
+                break;
             };
-            let grad = self.set_grad_length(grad, step, &arg);
-            arg = arg - grad;
+            let next_arg = self.project(&arg - &grad);
+            let arg_change = (&next_arg - &arg).length();
+            arg = next_arg;
+            steps = step + 1;
             if self.check_regularization_stop(&arg) { 
-                self.log(&format!("Stopped by regularization check"));
+                reason = StopReason::Regularization;
                 break; 
             }
-            self.save_result((self.func)(&arg), arg.clone());
+            let value_prev = value;
+            value = problem.value(&arg);
+            if let Some(stop) = progress.step(steps, &arg, value, grad_norm, arg_change) {
+                reason = stop;
+                break;
+            }
+            let state = StopState {
+                step,
+                arg: &arg,
+                value,
+                value_prev,
+                arg_change,
+                evaluations: problem.evaluations(),
+                elapsed: start.elapsed()
+            };
+            if let Some(stop) = self.stop_criteria.check(&state) {
+                reason = stop;
+                break;
+            }
         }
-        self.result = self.results.get_optimal_result();
+        self.log(&format!("Gradient descent stopped by {:?} after {} steps", reason, steps));
         self.log(&format!("Gradient descent elapsed in {:?}", start.elapsed()));
+        progress.finish(RunSummary { reason, steps, evaluations: problem.evaluations(), elapsed: start.elapsed() }, self.bounds.as_ref())
     }
 
-    fn set_grad_length(&mut self, grad: Tensor<T>, step: i16, arg: &Tensor<T>) -> Tensor<T> {
+    fn is_second_order(&self) -> bool {
+        matches!(self.step_size, StepSize::Newton | StepSize::NewtonCG(_))
+    }
+
+    fn is_regularized(&self) -> bool {
+        self.regularization.is_some()
+    }
+}
+
+impl<'a, T> GradientDescent<'a, T> where T: Float + Sum + Send + Sync + Debug {
+    /// None when a line search step size finds no step
+    fn set_grad_length(&mut self, problem: &Problem<T>, grad: Tensor<T>, step: usize, arg: &Tensor<T>) -> Option<Tensor<T>> {
+        if let (true, Some(learning_rate)) = (self.update_rule.is_adaptive(), self.learning_rate(step)) {
+            return Some(self.update_rule.adapt(&mut self.update_state, &grad, arg, learning_rate));
+        }
         let gradient = match self.step_size {
             StepSize::OriginGrad => grad,
             StepSize::Fixed(size) => grad.set_length(size),
@@ -91,31 +144,80 @@ impl<'a, T> GradientDescent<'a, T> where T: Float + Sum + Debug {
                 grad.set_length(size)
             },
             StepSize::Newton => {
-                let hessian = match self.hessian {
-                    Some(hessian) => hessian(arg),
-                    None => hessian(self.func, arg, self.derivative_delta)
-                };
+                let hessian = problem.hessian(arg, self.derivative_delta, self.difference_scheme);
                 if grad.is_ket() {
-                    dot(&hessian.inverse().unwrap(), &grad)
+                    self.newton_direction(&hessian, &grad)
                 } else {
-                    dot(&hessian.inverse().unwrap(), &grad.to_ket()).to_bra()
+                    self.newton_direction(&hessian, &grad.to_ket()).to_bra()
                 }
-                
+            },
+            StepSize::NewtonCG(max_iterations) => {
+                let product = |v: &Tensor<T>| hessian_vector_product(&|x: &Tensor<T>| self.gradient_at(problem, x), arg, v, self.derivative_delta);
+                let grad_length = grad.length();
+                let tolerance = grad_length * grad_length.sqrt().min(T::from(0.5).unwrap());
+                conjugate_gradient(&product, &grad, max_iterations, tolerance)
+            },
+            StepSize::Backtracking { c, rho } => {
+                let search = backtracking(&|x: &Tensor<T>| problem.value(x), arg, &(&grad * -T::one()), &grad, c, rho);
+                return self.line_search_step(grad, search, step);
+            },
+            StepSize::Wolfe { c1, c2 } => {
+                let gradient = |x: &Tensor<T>| self.gradient_at(problem, x);
+                let search = wolfe(&|x: &Tensor<T>| problem.value(x), &gradient, arg, &(&grad * -T::one()), &grad, c1, c2);
+                return self.line_search_step(grad, search, step);
             }
         };
-        self.apply_momentum_acceleration(gradient, step)
+        let gradient = match self.update_rule {
+            UpdateRule::Momentum => self.apply_momentum_acceleration(gradient, step),
+            UpdateRule::Nesterov { .. } => self.update_rule.accelerate(&mut self.update_state, gradient),
+            _ => gradient
+        };
+        Some(gradient)
     }
 
-    fn save_result(&mut self, value: T, arg: Tensor<T>) {
-        let result = ResultEntry { value, arg };
-        if self.save_progress {
-            self.results.add(result);
-        } else {
-            self.results.add_if_optimal(result);
+    /// Learning rate of adaptive update rules, second-order and line search steps don't have one
+    fn learning_rate(&self, step: usize) -> Option<T> {
+        match self.step_size {
+            StepSize::OriginGrad => Some(T::one()),
+            StepSize::Fixed(size) => Some(size),
+            StepSize::Decrement(size) => Some(size / T::from(step + 1).unwrap()),
+            _ => None
         }
     }
 
-    fn apply_momentum_acceleration(&mut self, grad: Tensor<T>, step: i16) -> Tensor<T>{
+    /// Line search steps are already scaled to decrease f, momentum is not applied to them
+    fn line_search_step(&mut self, grad: Tensor<T>, search: LineSearchResult<T>, step: usize) -> Option<Tensor<T>> {
+        self.log(&format!(
+            "Step {}: step length {:?} after {} function and {} gradient evaluations",
+            step, search.alpha, search.function_evaluations, search.gradient_evaluations
+        ));
+        if !search.converged {
+            self.log(&format!("Step {step}: line search failed"));
+            return None;
+        }
+        let result = grad * search.alpha;
+        self.grad_prev = result.clone();
+        Some(result)
+    }
+
+    fn gradient_at(&self, problem: &Problem<T>, arg: &Tensor<T>) -> Tensor<T> {
+        problem.gradient(arg, self.derivative_delta, self.difference_scheme)
+    }
+
+    fn newton_direction(&self, hessian: &Tensor<T>, grad: &Tensor<T>) -> Tensor<T> {
+        if let Ok(direction) = hessian.cholesky().and_then(|cholesky| cholesky.solve(grad)) {
+            return direction;
+        }
+        match hessian.solve(grad) {
+            Ok(direction) => direction,
+            Err(error) => {
+                self.log(&format!("{error} (cond = {:?}), pseudo-inverse is used", hessian.cond()));
+                dot(&hessian.pinv(None), grad)
+            }
+        }
+    }
+
+    fn apply_momentum_acceleration(&mut self, grad: Tensor<T>, step: usize) -> Tensor<T>{
         let result = if step == 0 { 
             grad
         } else { 
@@ -125,6 +227,20 @@ impl<'a, T> GradientDescent<'a, T> where T: Float + Sum + Debug {
         result
     }
 
+    fn project(&self, arg: Tensor<T>) -> Tensor<T> {
+        match &self.bounds {
+            Some(bounds) => bounds.project(&arg),
+            None => arg
+        }
+    }
+
+    fn projected_gradient(&self, arg: &Tensor<T>, grad: &Tensor<T>) -> Tensor<T> {
+        match &self.bounds {
+            Some(bounds) => bounds.projected_gradient(arg, grad),
+            None => grad.clone()
+        }
+    }
+
     fn check_regularization_stop(&self, arg: &Tensor<T>) -> bool {
         match &self.regularization {
             None => false,
@@ -139,26 +255,17 @@ impl<'a, T> GradientDescent<'a, T> where T: Float + Sum + Debug {
             println!("{}", message);
         }
     }
-
-    fn create_progress_bar(&self) -> Option<ProgressBar> {
-        let count = self.step_count.to_u64().unwrap();
-        if self.verbose {
-            Some(ProgressBar::new(count))
-        } else {
-            None
-        }
-    }
-
-    fn inc_progressbar(&self, bar: &Option<ProgressBar>) {
-        if let Some(ref bar) = bar {
-            bar.inc(1);
-        }
-    }
 }
 
 #[cfg(test)]
 mod tests {
-    use super::{GradientDescent, StepSize};
+    use num::Float;
+    use std::iter::Sum;
+    use std::time::Duration;
+    use super::{DifferenceScheme, GradientDescent, Optimizer, Problem, StepSize, UpdateRule};
+    use std::cell::RefCell;
+    use super::{StopCriteria, StopReason, StopState};
+    use super::super::{gradient_exact, ActiveBound, Bounds, Dual, EarlyStop, History};
     use tensor::{Tensor, Vector, assert_near};
 
     fn f(x: &Tensor) -> f64 {
@@ -173,14 +280,9 @@ mod tests {
 
     #[test]
     fn gradient_descent_analytic_grad() {
-        let mut optimizator = GradientDescent {
-            func: &f,
-            gradient: Some(&grad_f),
-            start_point: Vector::ket(vec![3.0, 3.0]),
-            ..Default::default()
-        };
-        optimizator.run();
-        let result = optimizator.result.unwrap();
+        let problem = Problem::new(f, Vector::ket(vec![3.0, 3.0])).with_gradient(grad_f);
+        let mut optimizator = GradientDescent::default();
+        let result = optimizator.minimize(&problem);
         let arg_expected = Vector::ket(vec![0.0, 0.0]);
         assert!(f64::abs(result.value - 2.0) < 0.001);
         assert_near!(result.arg, arg_expected, 0.001)
@@ -188,13 +290,9 @@ mod tests {
 
     #[test]
     fn gradient_descent_numeric_grad() {
-        let mut optimizator = GradientDescent {
-            func: &f,
-            start_point: Vector::ket(vec![3.0, 3.0]),
-            ..Default::default()
-        };
-        optimizator.run();
-        let result = optimizator.result.unwrap();
+        let problem = Problem::new(f, Vector::ket(vec![3.0, 3.0]));
+        let mut optimizator = GradientDescent::default();
+        let result = optimizator.minimize(&problem);
         let arg_expected = Vector::ket(vec![0.0, 0.0]);
         assert!(f64::abs(result.value - 2.0) < 0.001);
         assert_near!(result.arg, arg_expected, 0.001)
@@ -203,18 +301,226 @@ mod tests {
     //#[ignore]
     #[test]
     fn gradient_descent_newton() {
+        let problem = Problem::new(f, Vector::ket(vec![3.0, 3.0]));
         let mut optimizator = GradientDescent {
-            func: &f,
-            start_point: Vector::ket(vec![3.0, 3.0]),
             step_size: StepSize::Newton,
             step_count: 1,
             ..Default::default()
         };
-        optimizator.run();
-        let result = optimizator.result.unwrap();
+        let result = optimizator.minimize(&problem);
         let arg_expected = Vector::ket(vec![0.0, 0.0]);
         println!("{}", result.value);
         assert!(f64::abs(result.value - 2.0) < 0.01);
         assert_near!(result.arg, arg_expected, 0.01)
     }
+
+    #[test]
+    fn gradient_descent_newton_central() {
+        let problem = Problem::new(f, Vector::ket(vec![3.0, 3.0]));
+        let mut optimizator = GradientDescent {
+            step_size: StepSize::Newton,
+            step_count: 1,
+            difference_scheme: DifferenceScheme::Central,
+            ..Default::default()
+        };
+        let result = optimizator.minimize(&problem);
+        assert_near!(result.arg, Vector::ket(vec![0.0, 0.0]), 1e-6)
+    }
+
+    #[test]
+    fn gradient_descent_newton_cg() {
+        let problem = Problem::new(f, Vector::ket(vec![3.0, 3.0]));
+        let mut optimizator = GradientDescent {
+            step_size: StepSize::NewtonCG(10),
+            step_count: 1,
+            ..Default::default()
+        };
+        let result = optimizator.minimize(&problem);
+        assert_near!(result.arg, Vector::ket(vec![0.0, 0.0]), 0.01)
+    }
+
+    fn coupled<T: Float + Sum>(x: &Tensor<T>) -> T {
+        let sum = x.data.iter().copied().sum::<T>();
+        x.data.iter()
+            .enumerate()
+            .map(|(i, &value)| (value - T::from(i).unwrap()).cosh())
+            .sum::<T>() + sum * sum
+    }
+
+    #[test]
+    fn gradient_descent_newton_cg_convex() {
+        let problem = Problem::new(coupled::<f64>, Vector::bra(vec![1.0; 6]));
+        let mut optimizator = GradientDescent {
+            step_size: StepSize::NewtonCG(6),
+            difference_scheme: DifferenceScheme::Central,
+            betta: 0.0,
+            step_count: 20,
+            ..Default::default()
+        };
+        let result = optimizator.minimize(&problem);
+        let grad = gradient_exact(&coupled::<Dual<f64>>, &result.arg);
+        assert!(grad.length() < 1e-5, "{:?}", grad);
+    }
+
+    fn ill_conditioned(x: &Tensor) -> f64 {
+        x.get_v(0).powi(2) + 50.0 * (x.get_v(1) - 1.0).powi(2)
+    }
+
+    fn grad_ill_conditioned(x: &Tensor) -> Tensor {
+        Vector::ket(vec![2.0 * x.get_v(0), 100.0 * (x.get_v(1) - 1.0)])
+    }
+
+    #[test]
+    fn gradient_descent_backtracking() {
+        let problem = Problem::new(ill_conditioned, Vector::ket(vec![3.0, 3.0]));
+        let mut optimizator = GradientDescent {
+            step_size: StepSize::Backtracking { c: 1e-4, rho: 0.5 },
+            difference_scheme: DifferenceScheme::Central,
+            step_count: 500,
+            ..Default::default()
+        };
+        let result = optimizator.minimize(&problem);
+        assert_near!(result.arg, Vector::ket(vec![0.0, 1.0]), 0.001)
+    }
+
+    #[test]
+    fn gradient_descent_wolfe() {
+        let problem = Problem::new(ill_conditioned, Vector::ket(vec![3.0, 3.0]));
+        let mut optimizator = GradientDescent {
+            step_size: StepSize::Wolfe { c1: 1e-4, c2: 0.4 },
+            difference_scheme: DifferenceScheme::Central,
+            step_count: 500,
+            ..Default::default()
+        };
+        let result = optimizator.minimize(&problem);
+        assert_near!(result.arg, Vector::ket(vec![0.0, 1.0]), 0.001)
+    }
+
+    #[test]
+    fn gradient_descent_line_search_failure() {
+        let wrong_gradient = |x: &Tensor| Vector::ket(vec![-2.0 * x.get_v(0)]);
+        let problem = Problem::new(|x: &Tensor| x.get_v(0).powi(2), Vector::ket(vec![1.0])).with_gradient(wrong_gradient);
+        let mut optimizator = GradientDescent {
+            step_size: StepSize::Backtracking { c: 1e-4, rho: 0.5 },
+            ..Default::default()
+        };
+        let result = optimizator.minimize(&problem);
+        assert_eq!(result.summary.reason, StopReason::LineSearch);
+        assert_eq!(result.summary.steps, 0);
+        assert_eq!(result.arg, Vector::ket(vec![1.0]));
+    }
+
+    #[test]
+    fn gradient_descent_update_rules() {
+        let rules = [
+            UpdateRule::nesterov(),
+            UpdateRule::adam(),
+            UpdateRule::adam_w(1e-4),
+            UpdateRule::rms_prop(),
+            UpdateRule::Adagrad { epsilon: 1e-8 }
+        ];
+        for rule in rules {
+            let step_size = match rule {
+                UpdateRule::Nesterov { .. } => StepSize::Decrement(0.5),
+                UpdateRule::Adagrad { .. } => StepSize::Fixed(1.0),
+                _ => StepSize::Fixed(0.01)
+            };
+            let problem = Problem::new(ill_conditioned, Vector::ket(vec![3.0, 3.0])).with_gradient(grad_ill_conditioned);
+            let mut optimizator = GradientDescent {
+                step_size,
+                update_rule: rule.clone(),
+                ..Default::default()
+            };
+            let result = optimizator.minimize(&problem);
+            assert!(result.value < 1e-5, "{:?}: {}", rule, result.value);
+        }
+    }
+
+    #[test]
+    fn gradient_descent_stop_criteria() {
+        let run = |stop_criteria| {
+            let problem = Problem::new(ill_conditioned, Vector::ket(vec![3.0, 3.0]));
+            let mut optimizator = GradientDescent {
+                step_size: StepSize::Backtracking { c: 1e-4, rho: 0.5 },
+                difference_scheme: DifferenceScheme::Central,
+                step_count: 40000,
+                stop_criteria,
+                ..Default::default()
+            };
+            optimizator.minimize(&problem)
+        };
+
+        let result = run(StopCriteria::default());
+        assert_eq!(result.summary.reason, StopReason::StepCount);
+        assert_eq!(result.summary.steps, 40000);
+
+        let result = run(StopCriteria { gradient_norm: Some(1e-6), ..Default::default() });
+        assert_eq!(result.summary.reason, StopReason::GradientNorm);
+        assert!(result.summary.steps < 1000);
+        assert_near!(result.arg, Vector::ket(vec![0.0, 1.0]), 1e-6);
+
+        let result = run(StopCriteria { function_change: Some(1e-3), ..Default::default() });
+        assert_eq!(result.summary.reason, StopReason::FunctionChange);
+
+        let result = run(StopCriteria { arg_change: Some(1e-8), ..Default::default() });
+        assert_eq!(result.summary.reason, StopReason::ArgChange);
+
+        let result = run(StopCriteria { max_evaluations: Some(100), ..Default::default() });
+        assert_eq!(result.summary.reason, StopReason::MaxEvaluations);
+        assert!(result.summary.evaluations >= 100 && result.summary.evaluations < 120);
+
+        let result = run(StopCriteria { timeout: Some(Duration::ZERO), ..Default::default() });
+        assert_eq!((result.summary.reason, result.summary.steps), (StopReason::Timeout, 1));
+
+        let below = |state: &StopState<f64>| state.value < 2.0;
+        let result = run(StopCriteria { predicate: Some(&below), ..Default::default() });
+        assert_eq!(result.summary.reason, StopReason::Predicate);
+        assert!(result.value < 2.0);
+    }
+
+    #[test]
+    fn gradient_descent_newton_singular_hessian() {
+        let flat = |x: &Tensor| 2.0 + x.get_v(0).powi(2);
+        let problem = Problem::new(flat, Vector::ket(vec![3.0, 3.0]));
+        let mut optimizator = GradientDescent {
+            step_size: StepSize::Newton,
+            step_count: 1,
+            ..Default::default()
+        };
+        let result = optimizator.minimize(&problem);
+        let arg_expected = Vector::ket(vec![0.0, 3.0]);
+        assert!(f64::abs(result.value - 2.0) < 0.01);
+        assert_near!(result.arg, arg_expected, 0.01)
+    }
+
+    #[test]
+    fn gradient_descent_observers() {
+        let problem = Problem::new(f, Vector::ket(vec![3.0, 3.0])).with_gradient(grad_f);
+        let history = RefCell::new(History::new());
+        let early_stop = RefCell::new(EarlyStop::new(5, 1e-6));
+        let mut optimizator = GradientDescent {
+            observers: vec![&history, &early_stop],
+            ..Default::default()
+        };
+        let result = optimizator.minimize(&problem);
+        assert_eq!(result.summary.reason, StopReason::Observer);
+        let history = history.borrow();
+        assert_eq!(history.entries.len(), result.summary.steps + 1);
+        assert_eq!(history.best().unwrap().value, result.value);
+        assert!((history.entries[1].grad_norm - 6.0 * 2f64.sqrt()).abs() < 1e-12);
+    }
+
+    #[test]
+    fn gradient_descent_bounds() {
+        let shifted = |x: &Tensor| (x.get_v(0) + 1.0).powi(2) + (x.get_v(1) - 2.0).powi(2);
+        let problem = Problem::new(shifted, Vector::ket(vec![3.0, 3.0]));
+        let mut optimizator = GradientDescent {
+            step_size: StepSize::Fixed(0.01),
+            bounds: Some(Bounds::non_negative(2)),
+            ..Default::default()
+        };
+        let result = optimizator.minimize(&problem);
+        assert_near!(result.arg, Vector::ket(vec![0.0, 2.0]), 0.01);
+        assert_eq!(result.active_bounds, vec![ActiveBound::Lower(0)]);
+    }
 }
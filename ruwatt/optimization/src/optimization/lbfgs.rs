@@ -0,0 +1,259 @@
+use num::Float;
+use std::{collections::VecDeque, fmt::Debug, iter::Sum, time::Instant};
+use tensor::Tensor;
+use super::conjugate_gradient::inner;
+use super::{wolfe, Bounds, DifferenceScheme, LineSearchResult, ObserverRef, Progress};
+use super::{OptimizationResult, Optimizer, Problem, RunSummary, StopCriteria, StopReason, StopState};
+
+/// Limited-memory BFGS: the inverse Hessian is never stored, the direction
+/// is recovered from the last `history` pairs of steps and gradient differences
+/// with the two-loop recursion, so memory and work per step are O(history·n).
+///
+/// With `bounds` it works in the spirit of L-BFGS-B: coordinates held at a limit by the gradient
+/// are fixed for the step, the direction is built on the free ones and the Wolfe search runs
+/// along the projected path P(x + α·d).
+#[derive(Clone)]
+pub struct Lbfgs<'a, T> where T: Float + Debug {
+    pub step_count: usize,
+    pub stop_criteria: StopCriteria<'a, T>,
+    pub history: usize,
+    pub c1: T,
+    pub c2: T,
+    pub derivative_delta: T,
+    pub difference_scheme: DifferenceScheme,
+    pub observers: Vec<ObserverRef<'a, T>>,
+    pub verbose: bool,
+    pub bounds: Option<Bounds<T>>
+}
+
+impl<'a, T> Default for Lbfgs<'a, T> where T: Float + Debug {
+    fn default() -> Self {
+        Self {
+            step_count: 100,
+            stop_criteria: StopCriteria::default(),
+            history: 10,
+            c1: T::from(1e-4).unwrap(),
+            c2: T::from(0.9).unwrap(),
+            derivative_delta: T::from(0.0001).unwrap(),
+            difference_scheme: DifferenceScheme::Central,
+            observers: vec![],
+            verbose: false,
+            bounds: None
+        }
+    }
+}
+
+struct Correction<T> where T: Float {
+    s: Tensor<T>,
+    y: Tensor<T>,
+    rho: T
+}
+
+impl<'a, T> Optimizer<T> for Lbfgs<'a, T> where T: Float + Sum + Debug {
+    fn minimize(&mut self, problem: &Problem<T>) -> OptimizationResult<T> {
+        self.log("L-BFGS started");
+        let start = Instant::now();
+
+        let mut corrections: VecDeque<Correction<T>> = VecDeque::with_capacity(self.history);
+        let mut arg = match &self.bounds {
+            Some(bounds) => bounds.project(&problem.start_point),
+            None => problem.start_point.clone()
+        };
+        let mut reason = StopReason::StepCount;
+        let mut steps = 0;
+        problem.reset_evaluations();
+        let mut grad = self.gradient_at(problem, &arg);
+        let mut value = problem.value(&arg);
+        let mut progress = Progress::start(&self.observers, &arg, value);
+        for step in 0..self.step_count {
+            let projected_grad = match &self.bounds {
+                Some(bounds) => bounds.projected_gradient(&arg, &grad),
+                None => grad.clone()
+            };
+            if projected_grad.length() <= T::epsilon() {
+                reason = StopReason::GradientNorm;
+                break;
+            }
+            if let Some(stop) = self.stop_criteria.check_gradient(&projected_grad) {
+                reason = stop;
+                break;
+            }
+            let free = self.bounds.as_ref().map(|bounds| bounds.free(&arg, &grad));
+            let free_grad = restrict(&grad, &free);
+            let mut direction = restrict(&two_loop(&corrections, &free_grad), &free) * -T::one();
+            if inner(&direction, &grad) >= T::zero() {
+                self.log(&format!("Step {step}: not a descent direction, history is cleared"));
+                corrections.clear();
+                direction = &free_grad * -T::one();
+            }
+            let search = self.line_search(problem, &arg, &direction, &grad);
+            self.log(&format!(
+                "Step {}: step length {:?} after {} function and {} gradient evaluations",
+                step, search.alpha, search.function_evaluations, search.gradient_evaluations
+            ));
+            if !search.converged {
+                if corrections.is_empty() {
+                    reason = StopReason::LineSearch;
+                    break;
+                }
+                self.log(&format!("Step {step}: line search failed, history is cleared"));
+                corrections.clear();
+                continue;
+            }
+
+            let grad_norm = grad.length();
+            let next_arg = match &self.bounds {
+                Some(bounds) => bounds.project(&(&arg + &(&direction * search.alpha))),
+                None => &arg + &(&direction * search.alpha)
+            };
+            let s = &next_arg - &arg;
+            let arg_change = s.length();
+            let next_grad = self.gradient_at(problem, &next_arg);
+            let y = &next_grad - &grad;
+            let curvature = inner(&y, &s);
+            if curvature > T::zero() && self.history > 0 {
+                if corrections.len() == self.history {
+                    corrections.pop_front();
+                }
+                corrections.push_back(Correction { s, y, rho: curvature.recip() });
+            }
+            arg = next_arg;
+            grad = next_grad;
+            steps = step + 1;
+            let value_prev = value;
+            value = problem.value(&arg);
+            if let Some(stop) = progress.step(steps, &arg, value, grad_norm, arg_change) {
+                reason = stop;
+                break;
+            }
+            let state = StopState {
+                step,
+                arg: &arg,
+                value,
+                value_prev,
+                arg_change,
+                evaluations: problem.evaluations(),
+                elapsed: start.elapsed()
+            };
+            if let Some(stop) = self.stop_criteria.check(&state) {
+                reason = stop;
+                break;
+            }
+        }
+        self.log(&format!("L-BFGS stopped by {:?} after {} steps", reason, steps));
+        self.log(&format!("L-BFGS elapsed in {:?}", start.elapsed()));
+        progress.finish(RunSummary { reason, steps, evaluations: problem.evaluations(), elapsed: start.elapsed() }, self.bounds.as_ref())
+    }
+}
+
+impl<'a, T> Lbfgs<'a, T> where T: Float + Sum + Debug {
+    fn line_search(&self, problem: &Problem<T>, arg: &Tensor<T>, direction: &Tensor<T>, grad: &Tensor<T>) -> LineSearchResult<T> {
+        match &self.bounds {
+            Some(bounds) => {
+                let f = |x: &Tensor<T>| problem.value(&bounds.project(x));
+                let gradient = |x: &Tensor<T>| self.gradient_at(problem, &bounds.project(x));
+                wolfe(&f, &gradient, arg, direction, grad, self.c1, self.c2)
+            },
+            None => wolfe(&|x: &Tensor<T>| problem.value(x), &|x: &Tensor<T>| self.gradient_at(problem, x), arg, direction, grad, self.c1, self.c2)
+        }
+    }
+
+    fn gradient_at(&self, problem: &Problem<T>, arg: &Tensor<T>) -> Tensor<T> {
+        problem.gradient(arg, self.derivative_delta, self.difference_scheme)
+    }
+
+    fn log(&self, message: &str) {
+        if self.verbose {
+            println!("{}", message);
+        }
+    }
+}
+
+/// Zeroes the coordinates that are not free, all of them are free without bounds
+fn restrict<T>(tensor: &Tensor<T>, free: &Option<Vec<bool>>) -> Tensor<T> where T: Float {
+    match free {
+        Some(free) => {
+            let data = tensor.data.iter()
+                .zip(free.iter())
+                .map(|(&value, &free)| if free { value } else { T::zero() })
+                .collect();
+            Tensor { shape: tensor.shape.clone(), data }
+        },
+        None => tensor.clone()
+    }
+}
+
+/// H·g from the stored corrections, initial H₀ = (sᵀy / yᵀy)·I of the newest pair
+fn two_loop<T>(corrections: &VecDeque<Correction<T>>, grad: &Tensor<T>) -> Tensor<T> where T: Float + Sum {
+    let mut q = grad.clone();
+    let mut alphas = Vec::with_capacity(corrections.len());
+    for correction in corrections.iter().rev() {
+        let alpha = correction.rho * inner(&correction.s, &q);
+        q = &q - &(&correction.y * alpha);
+        alphas.push(alpha);
+    }
+    if let Some(newest) = corrections.back() {
+        q = q * (newest.rho.recip() / inner(&newest.y, &newest.y));
+    }
+    for (correction, &alpha) in corrections.iter().zip(alphas.iter().rev()) {
+        let beta = correction.rho * inner(&correction.y, &q);
+        q = &q + &(&correction.s * (alpha - beta));
+    }
+    q
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Lbfgs, Optimizer, Problem};
+    use super::super::{ActiveBound, Bounds};
+    use tensor::{Tensor, Vector, assert_near};
+
+    fn f(x: &Tensor) -> f64 {
+        2.0 + x.get_v(0).powi(2) + x.get_v(1).powi(2)
+    }
+
+    fn rosenbrock(x: &Tensor) -> f64 {
+        (0..x.dim() - 1)
+            .map(|i| (1.0 - x.get_v(i)).powi(2) + 100.0 * (x.get_v(i + 1) - x.get_v(i).powi(2)).powi(2))
+            .sum()
+    }
+
+    #[test]
+    fn lbfgs_quadratic() {
+        let problem = Problem::new(f, Vector::ket(vec![3.0, 3.0]));
+        let mut optimizator = Lbfgs {
+            step_count: 10,
+            ..Default::default()
+        };
+        let result = optimizator.minimize(&problem);
+        assert!(f64::abs(result.value - 2.0) < 1e-10);
+        assert_near!(result.arg, Vector::ket(vec![0.0, 0.0]), 1e-5)
+    }
+
+    #[test]
+    fn lbfgs_rosenbrock() {
+        let problem = Problem::new(rosenbrock, Vector::bra(vec![-1.2, 1.0, -1.2, 1.0, -1.2, 1.0]));
+        let mut optimizator = Lbfgs {
+            history: 5,
+            step_count: 200,
+            ..Default::default()
+        };
+        let result = optimizator.minimize(&problem);
+        assert!(result.value < 1e-8, "{}", result.value);
+        assert_near!(result.arg, Vector::bra(vec![1.0; 6]), 1e-4)
+    }
+
+    #[test]
+    fn lbfgs_bounded_quadratic() {
+        let coupled = |x: &Tensor| (x.get_v(0) - 2.0).powi(2) + (x.get_v(1) + 1.0).powi(2) + x.get_v(0) * x.get_v(1);
+        let problem = Problem::new(coupled, Vector::ket(vec![0.5, 0.5]));
+        let mut optimizator = Lbfgs {
+            bounds: Some(Bounds::unit(2)),
+            ..Default::default()
+        };
+        let result = optimizator.minimize(&problem);
+        assert_near!(result.arg, Vector::ket(vec![1.0, 0.0]), 1e-10);
+        assert!(f64::abs(result.value - 2.0) < 1e-10);
+        assert_eq!(result.active_bounds, vec![ActiveBound::Upper(0), ActiveBound::Lower(1)]);
+    }
+}
@@ -0,0 +1,170 @@
+use num::Float;
+use std::cell::Cell;
+use std::iter::Sum;
+use tensor::Tensor;
+use super::conjugate_gradient::inner;
+
+const MAX_LINE_SEARCH_STEPS: usize = 50;
+
+/// Step length chosen along a descent direction
+#[derive(Debug, Clone, PartialEq)]
+pub struct LineSearchResult<T> where T: Float {
+    pub alpha: T,
+    pub function_evaluations: usize,
+    pub gradient_evaluations: usize,
+    /// False when the conditions didn't hold within the step budget, `alpha` is then not a usable step
+    pub converged: bool
+}
+
+/// Backtracking from α = 1 until the Armijo condition
+/// f(x + α·d) ≤ f(x) + c·α·∇f(x)ᵀd holds, shrinking α by `rho` each time.
+pub fn backtracking<T>(f: &dyn Fn(&Tensor<T>) -> T, point: &Tensor<T>, direction: &Tensor<T>, grad: &Tensor<T>, c: T, rho: T) -> LineSearchResult<T> where T: Float + Sum {
+    let value = f(point);
+    let slope = inner(grad, direction);
+    let mut alpha = T::one();
+    let mut function_evaluations = 1;
+    let mut converged = false;
+    for _ in 0..MAX_LINE_SEARCH_STEPS {
+        function_evaluations += 1;
+        if f(&(point + &(direction * alpha))) <= value + c * alpha * slope {
+            converged = true;
+            break;
+        }
+        alpha = alpha * rho;
+    }
+    LineSearchResult { alpha, function_evaluations, gradient_evaluations: 0, converged }
+}
+
+/// Step length satisfying the strong Wolfe conditions
+/// f(x + α·d) ≤ f(x) + c1·α·∇f(x)ᵀd and |∇f(x + α·d)ᵀd| ≤ c2·|∇f(x)ᵀd|:
+/// α doubles from 1 until the minimum is bracketed, then the bracket is bisected.
+pub fn wolfe<T>(f: &dyn Fn(&Tensor<T>) -> T, gradient: &dyn Fn(&Tensor<T>) -> Tensor<T>, point: &Tensor<T>, direction: &Tensor<T>, grad: &Tensor<T>, c1: T, c2: T) -> LineSearchResult<T> where T: Float + Sum {
+    let function_evaluations = Cell::new(0);
+    let gradient_evaluations = Cell::new(0);
+    let phi = |alpha: T| {
+        function_evaluations.set(function_evaluations.get() + 1);
+        f(&(point + &(direction * alpha)))
+    };
+    let dphi = |alpha: T| {
+        gradient_evaluations.set(gradient_evaluations.get() + 1);
+        inner(&gradient(&(point + &(direction * alpha))), direction)
+    };
+    let result = |(alpha, converged): (T, bool)| LineSearchResult {
+        alpha,
+        function_evaluations: function_evaluations.get(),
+        gradient_evaluations: gradient_evaluations.get(),
+        converged
+    };
+
+    let phi_0 = phi(T::zero());
+    let dphi_0 = inner(grad, direction);
+    let sufficient_decrease = |alpha: T, value: T| value <= phi_0 + c1 * alpha * dphi_0;
+    let curvature = |slope: T| slope.abs() <= -c2 * dphi_0;
+
+    let zoom = |mut lo: T, mut phi_lo: T, mut hi: T| {
+        let mut alpha = lo;
+        for _ in 0..MAX_LINE_SEARCH_STEPS {
+            alpha = (lo + hi) / T::from(2).unwrap();
+            let value = phi(alpha);
+            if !sufficient_decrease(alpha, value) || value >= phi_lo {
+                hi = alpha;
+                continue;
+            }
+            let slope = dphi(alpha);
+            if curvature(slope) {
+                return (alpha, true);
+            }
+            if slope * (hi - lo) >= T::zero() {
+                hi = lo;
+            }
+            lo = alpha;
+            phi_lo = value;
+        }
+        (alpha, false)
+    };
+
+    let mut alpha_prev = T::zero();
+    let mut phi_prev = phi_0;
+    let mut alpha = T::one();
+    for i in 0..MAX_LINE_SEARCH_STEPS {
+        let value = phi(alpha);
+        if !sufficient_decrease(alpha, value) || (i > 0 && value >= phi_prev) {
+            return result(zoom(alpha_prev, phi_prev, alpha));
+        }
+        let slope = dphi(alpha);
+        if curvature(slope) {
+            return result((alpha, true));
+        }
+        if slope >= T::zero() {
+            return result(zoom(alpha, value, alpha_prev));
+        }
+        alpha_prev = alpha;
+        phi_prev = value;
+        alpha = alpha * T::from(2).unwrap();
+    }
+    result((alpha, false))
+}
+
+#[cfg(test)]
+mod tests {
+    use tensor::{Tensor, Vector};
+    use super::{backtracking, wolfe};
+
+    fn f(x: &Tensor) -> f64 {
+        (x.get_v(0) - 1.0).powi(4) + x.get_v(1).powi(2)
+    }
+
+    fn grad_f(x: &Tensor) -> Tensor {
+        Vector::ket(vec![4.0 * (x.get_v(0) - 1.0).powi(3), 2.0 * x.get_v(1)])
+    }
+
+    #[test]
+    fn armijo() {
+        let point = Vector::ket(vec![3.0, 1.0]);
+        let grad = grad_f(&point);
+        let direction = &grad * -1.0;
+        let recieved = backtracking(&f, &point, &direction, &grad, 1e-4, 0.5);
+        assert_eq!(recieved.alpha, 0.125);
+        assert_eq!(recieved.function_evaluations, 5);
+        assert!(recieved.converged);
+        assert!(f(&(&point + &(&direction * recieved.alpha))) < f(&point));
+    }
+
+    #[test]
+    fn strong_wolfe() {
+        let (c1, c2) = (1e-4, 0.9);
+        let point = Vector::ket(vec![3.0, 1.0]);
+        let grad = grad_f(&point);
+        let direction = &grad * -1.0;
+        let recieved = wolfe(&f, &grad_f, &point, &direction, &grad, c1, c2);
+        let next = &point + &(&direction * recieved.alpha);
+        let slope = |g: &Tensor| g.data.iter().zip(direction.data.iter()).map(|(a, b)| a * b).sum::<f64>();
+        assert!(f(&next) <= f(&point) + c1 * recieved.alpha * slope(&grad));
+        assert!(slope(&grad_f(&next)).abs() <= c2 * slope(&grad).abs());
+        assert!(recieved.function_evaluations > 1 && recieved.gradient_evaluations > 0);
+        assert!(recieved.converged);
+    }
+
+    #[test]
+    fn wolfe_expands_short_steps() {
+        let f = |x: &Tensor| (x.get_v(0) - 10.0).powi(2);
+        let grad_f = |x: &Tensor| Vector::ket(vec![2.0 * (x.get_v(0) - 10.0)]);
+        let point = Vector::ket(vec![0.0]);
+        let grad = grad_f(&point);
+        let direction = Vector::ket(vec![1.0]);
+        let recieved = wolfe(&f, &grad_f, &point, &direction, &grad, 1e-4, 0.1);
+        assert!((recieved.alpha - 10.0).abs() <= 2.0, "{}", recieved.alpha);
+    }
+
+    #[test]
+    fn failed_searches_are_reported() {
+        let f = |x: &Tensor| -x.get_v(0);
+        let grad_f = |_: &Tensor| Vector::ket(vec![-1.0]);
+        let point = Vector::ket(vec![0.0]);
+        let grad = grad_f(&point);
+        let uphill = Vector::ket(vec![-1.0]);
+        assert!(!backtracking(&f, &point, &uphill, &grad, 1e-4, 0.5).converged);
+        let unbounded = wolfe(&f, &grad_f, &point, &Vector::ket(vec![1.0]), &grad, 1e-4, 0.9);
+        assert!(!unbounded.converged);
+    }
+}
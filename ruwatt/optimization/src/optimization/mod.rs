@@ -1,9 +1,37 @@
+pub mod augmented_lagrangian;
+pub mod bfgs;
+pub mod bounds;
+pub mod conjugate_gradient;
+pub mod coordinate_search;
 pub mod derivative;
-//pub mod derivative_new;
+pub mod dual;
 pub mod gradient_descent;
-//pub mod gradient_descent_new;
-pub mod result_logs;
+pub mod lbfgs;
+pub mod line_search;
+pub mod nelder_mead;
+pub mod observer;
+pub mod optimizer;
+pub mod random_search;
+pub mod stochastic_gradient_descent;
+pub mod stop_criteria;
+pub mod systemle;
+pub mod update_rule;
 
+pub use augmented_lagrangian::*;
+pub use bfgs::*;
+pub use bounds::*;
+pub use conjugate_gradient::*;
+pub use coordinate_search::*;
 pub use derivative::*;
+pub use dual::*;
 pub use gradient_descent::*;
-pub use result_logs::*;
+pub use lbfgs::*;
+pub use line_search::*;
+pub use nelder_mead::*;
+pub use observer::*;
+pub use optimizer::*;
+pub use random_search::*;
+pub use stochastic_gradient_descent::*;
+pub use stop_criteria::*;
+pub use systemle::*;
+pub use update_rule::*;
@@ -0,0 +1,182 @@
+use num::Float;
+use std::{cmp::Ordering, fmt::Debug, time::Instant};
+use tensor::Tensor;
+use super::{ObserverRef, OptimizationResult, Optimizer, Problem, Progress, RunSummary, StopCriteria, StopReason, StopState};
+
+/// Nelder–Mead downhill simplex, uses objective values only.
+/// Observers and stop criteria see the best vertex; for stop criteria `value_prev` is the worst vertex value
+/// and `arg_change` is the largest distance from the best vertex, so `function_change` and `arg_change`
+/// bound the spread of the simplex. The gradient norm reported to observers is always zero.
+#[derive(Clone)]
+pub struct NelderMead<'a, T> where T: Float + Debug {
+    pub step_count: usize,
+    pub stop_criteria: StopCriteria<'a, T>,
+    /// Edge of the initial simplex along every axis
+    pub step_size: T,
+    pub reflection: T,
+    pub expansion: T,
+    pub contraction: T,
+    pub shrink: T,
+    pub observers: Vec<ObserverRef<'a, T>>,
+    pub verbose: bool
+}
+
+impl<'a, T> Default for NelderMead<'a, T> where T: Float + Debug {
+    fn default() -> Self {
+        Self {
+            step_count: 1000,
+            stop_criteria: StopCriteria::default(),
+            step_size: T::one(),
+            reflection: T::one(),
+            expansion: T::from(2.0).unwrap(),
+            contraction: T::from(0.5).unwrap(),
+            shrink: T::from(0.5).unwrap(),
+            observers: vec![],
+            verbose: false
+        }
+    }
+}
+
+impl<'a, T> Optimizer<T> for NelderMead<'a, T> where T: Float + Debug {
+    fn minimize(&mut self, problem: &Problem<T>) -> OptimizationResult<T> {
+        assert!(!problem.start_point.data.is_empty(), "Nelder-Mead needs a start point with at least one coordinate");
+        self.log("Nelder-Mead started");
+        let start = Instant::now();
+
+        let mut reason = StopReason::StepCount;
+        let mut steps = 0;
+        problem.reset_evaluations();
+        let mut simplex = self.initial_simplex(problem);
+        let mut progress = Progress::start(&self.observers, &simplex[0].0, simplex[0].1);
+        for step in 0..self.step_count {
+            let best_prev = simplex[0].0.clone();
+            self.update(problem, &mut simplex);
+            sort(&mut simplex);
+            steps = step + 1;
+
+            let (best, value) = &simplex[0];
+            let step_len = (best - &best_prev).length();
+            if let Some(stop) = progress.step(steps, best, *value, T::zero(), step_len) {
+                reason = stop;
+                break;
+            }
+            let state = StopState {
+                step,
+                arg: best,
+                value: *value,
+                value_prev: simplex[simplex.len() - 1].1,
+                arg_change: simplex.iter().map(|(vertex, _)| (vertex - best).length()).fold(T::zero(), T::max),
+                evaluations: problem.evaluations(),
+                elapsed: start.elapsed()
+            };
+            if let Some(stop) = self.stop_criteria.check(&state) {
+                reason = stop;
+                break;
+            }
+        }
+        self.log(&format!("Nelder-Mead stopped by {:?} after {} steps", reason, steps));
+        self.log(&format!("Nelder-Mead elapsed in {:?}", start.elapsed()));
+        progress.finish(RunSummary { reason, steps, evaluations: problem.evaluations(), elapsed: start.elapsed() }, None)
+    }
+}
+
+impl<'a, T> NelderMead<'a, T> where T: Float + Debug {
+    fn initial_simplex(&self, problem: &Problem<T>) -> Vec<(Tensor<T>, T)> {
+        let start_point = &problem.start_point;
+        let mut simplex = vec![(start_point.clone(), problem.value(start_point))];
+        for index in 0..start_point.data.len() {
+            let mut vertex = start_point.clone();
+            vertex.data[index] = vertex.data[index] + self.step_size;
+            let value = problem.value(&vertex);
+            simplex.push((vertex, value));
+        }
+        sort(&mut simplex);
+        simplex
+    }
+
+    /// Replaces the worst vertex of the sorted simplex or shrinks it towards the best one
+    fn update(&self, problem: &Problem<T>, simplex: &mut [(Tensor<T>, T)]) {
+        let worst = simplex.len() - 1;
+        let centroid = simplex[..worst].iter()
+            .fold(Tensor::zeros(simplex[0].0.shape.clone()), |sum, (vertex, _)| &sum + vertex)
+            * (T::one() / T::from(worst).unwrap());
+        let towards = |vertex: &Tensor<T>, coefficient: T| &centroid + &((vertex - &centroid) * coefficient);
+
+        let reflected = towards(&simplex[worst].0, -self.reflection);
+        let reflected_value = problem.value(&reflected);
+        if reflected_value < simplex[0].1 {
+            let expanded = towards(&reflected, self.expansion);
+            let expanded_value = problem.value(&expanded);
+            simplex[worst] = if expanded_value < reflected_value { (expanded, expanded_value) } else { (reflected, reflected_value) };
+            return;
+        }
+        if reflected_value < simplex[worst - 1].1 {
+            simplex[worst] = (reflected, reflected_value);
+            return;
+        }
+        let (contracted, bound) = if reflected_value < simplex[worst].1 {
+            (towards(&reflected, self.contraction), reflected_value)
+        } else {
+            (towards(&simplex[worst].0, self.contraction), simplex[worst].1)
+        };
+        let contracted_value = problem.value(&contracted);
+        if contracted_value < bound {
+            simplex[worst] = (contracted, contracted_value);
+            return;
+        }
+        self.log("Contraction failed, simplex is shrunk");
+        let best = simplex[0].0.clone();
+        for (vertex, value) in simplex.iter_mut().skip(1) {
+            *vertex = &best + &((&*vertex - &best) * self.shrink);
+            *value = problem.value(vertex);
+        }
+    }
+
+    fn log(&self, message: &str) {
+        if self.verbose {
+            println!("{}", message);
+        }
+    }
+}
+
+fn sort<T>(simplex: &mut [(Tensor<T>, T)]) where T: Float {
+    simplex.sort_by(|a, b| a.1.partial_cmp(&b.1).unwrap_or(Ordering::Equal));
+}
+
+#[cfg(test)]
+mod tests {
+    use tensor::{Tensor, Vector, assert_near};
+    use super::{NelderMead, Optimizer, Problem, StopCriteria, StopReason};
+
+    fn rosenbrock(x: &Tensor) -> f64 {
+        (1.0 - x.get_v(0)).powi(2) + 100.0 * (x.get_v(1) - x.get_v(0).powi(2)).powi(2)
+    }
+
+    #[test]
+    fn nelder_mead_rosenbrock() {
+        let problem = Problem::new(rosenbrock, Vector::ket(vec![-1.2, 1.0]));
+        let mut optimizator = NelderMead {
+            stop_criteria: StopCriteria { arg_change: Some(1e-8), ..Default::default() },
+            ..Default::default()
+        };
+        let result = optimizator.minimize(&problem);
+        assert_eq!(result.summary.reason, StopReason::ArgChange);
+        assert_near!(result.arg, Vector::ket(vec![1.0, 1.0]), 1e-6);
+    }
+
+    #[test]
+    fn nelder_mead_non_smooth() {
+        let abs = |x: &Tensor| (x.get_v(0) - 1.0).abs() + 2.0 * (x.get_v(1) + 2.0).abs() + (x.get_v(2) - 0.5).abs();
+        let problem = Problem::new(abs, Vector::bra(vec![0.0; 3]));
+        let mut optimizator = NelderMead::default();
+        let result = optimizator.minimize(&problem);
+        assert_near!(result.arg, Vector::bra(vec![1.0, -2.0, 0.5]), 1e-6);
+    }
+
+    #[test]
+    #[should_panic(expected = "Nelder-Mead needs a start point with at least one coordinate")]
+    fn nelder_mead_empty_start_point() {
+        let problem = Problem::new(|_: &Tensor| 0.0, Vector::ket(vec![]));
+        NelderMead::default().minimize(&problem);
+    }
+}
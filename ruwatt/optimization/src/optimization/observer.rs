@@ -0,0 +1,283 @@
+use num::Float;
+use indicatif::ProgressBar;
+use std::{cell::RefCell, fs::File, io::{self, Write}};
+use tensor::Tensor;
+use super::{Bounds, OptimizationResult, RunSummary, StopReason};
+
+/// Hooks called by optimizers during a run.
+/// `step` in `on_step` is the number of steps taken so far, `grad_norm` is the norm of the gradient
+/// the step was computed from and `step_len` is the distance between the previous and the new point.
+pub trait OptimizationObserver<T> where T: Float {
+    fn on_start(&mut self, _arg: &Tensor<T>, _value: T) {}
+
+    fn on_step(&mut self, step: usize, arg: &Tensor<T>, value: T, grad_norm: T, step_len: T);
+
+    fn on_finish(&mut self, _result: &OptimizationResult<T>) {}
+
+    /// Checked after every step, the run ends with `StopReason::Observer` once it returns true
+    fn should_stop(&self) -> bool {
+        false
+    }
+}
+
+pub type ObserverRef<'a, T> = &'a RefCell<dyn OptimizationObserver<T> + 'a>;
+
+#[derive(Clone, Debug)]
+pub struct HistoryEntry<T> where T: Float {
+    pub step: usize,
+    pub arg: Tensor<T>,
+    pub value: T,
+    pub grad_norm: T,
+    pub step_len: T
+}
+
+/// Every point of the last run, the start point included as step 0 with zero gradient norm and step length
+#[derive(Clone, Debug)]
+pub struct History<T> where T: Float {
+    pub entries: Vec<HistoryEntry<T>>
+}
+
+impl<T> History<T> where T: Float {
+    pub fn new() -> Self {
+        Self { entries: vec![] }
+    }
+
+    pub fn best(&self) -> Option<&HistoryEntry<T>> {
+        self.entries.iter().fold(None, |best: Option<&HistoryEntry<T>>, entry| match best {
+            Some(best) if best.value <= entry.value => Some(best),
+            _ => Some(entry)
+        })
+    }
+
+    pub fn values(&self) -> Vec<T> {
+        self.entries.iter().map(|entry| entry.value).collect()
+    }
+}
+
+impl<T> Default for History<T> where T: Float {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> OptimizationObserver<T> for History<T> where T: Float {
+    fn on_start(&mut self, arg: &Tensor<T>, value: T) {
+        self.entries = vec![HistoryEntry { step: 0, arg: arg.clone(), value, grad_norm: T::zero(), step_len: T::zero() }];
+    }
+
+    fn on_step(&mut self, step: usize, arg: &Tensor<T>, value: T, grad_norm: T, step_len: T) {
+        self.entries.push(HistoryEntry { step, arg: arg.clone(), value, grad_norm, step_len });
+    }
+}
+
+/// Writes `step,value,grad_norm,step_len,x0,x1,…` rows, one per point
+pub struct CsvWriter<W> where W: Write {
+    pub writer: W,
+    /// First write error, the following rows are skipped once it is set
+    pub error: Option<io::Error>
+}
+
+impl<W> CsvWriter<W> where W: Write {
+    pub fn new(writer: W) -> Self {
+        Self { writer, error: None }
+    }
+
+    fn write_line(&mut self, values: Vec<String>) {
+        if self.error.is_none() {
+            let line = values.join(",") + "\n";
+            if let Err(error) = self.writer.write_all(line.as_bytes()) {
+                self.error = Some(error);
+            }
+        }
+    }
+
+    fn write_row<T>(&mut self, step: usize, arg: &Tensor<T>, value: T, grad_norm: T, step_len: T) where T: Float {
+        let mut values = vec![step.to_string()];
+        values.extend([value, grad_norm, step_len].iter().chain(arg.data.iter()).map(|value| format!("{}", value.to_f64().unwrap())));
+        self.write_line(values);
+    }
+}
+
+impl CsvWriter<File> {
+    pub fn create(file_name: &str) -> io::Result<Self> {
+        Ok(Self::new(File::create(file_name)?))
+    }
+}
+
+impl<T, W> OptimizationObserver<T> for CsvWriter<W> where T: Float, W: Write {
+    fn on_start(&mut self, arg: &Tensor<T>, value: T) {
+        let mut header: Vec<String> = ["step", "value", "grad_norm", "step_len"].iter().map(|name| name.to_string()).collect();
+        header.extend((0..arg.data.len()).map(|index| format!("x{index}")));
+        self.write_line(header);
+        self.write_row(0, arg, value, T::zero(), T::zero());
+    }
+
+    fn on_step(&mut self, step: usize, arg: &Tensor<T>, value: T, grad_norm: T, step_len: T) {
+        self.write_row(step, arg, value, grad_norm, step_len);
+    }
+
+    fn on_finish(&mut self, _result: &OptimizationResult<T>) {
+        if let (None, Err(error)) = (&self.error, self.writer.flush()) {
+            self.error = Some(error);
+        }
+    }
+}
+
+/// Terminal progress bar over a known step budget
+pub struct ProgressObserver {
+    pub step_count: usize,
+    bar: Option<ProgressBar>
+}
+
+impl ProgressObserver {
+    pub fn new(step_count: usize) -> Self {
+        Self { step_count, bar: None }
+    }
+}
+
+impl<T> OptimizationObserver<T> for ProgressObserver where T: Float {
+    fn on_start(&mut self, _arg: &Tensor<T>, _value: T) {
+        self.bar = Some(ProgressBar::new(self.step_count as u64));
+    }
+
+    fn on_step(&mut self, _step: usize, _arg: &Tensor<T>, _value: T, _grad_norm: T, _step_len: T) {
+        if let Some(ref bar) = self.bar {
+            bar.inc(1);
+        }
+    }
+
+    fn on_finish(&mut self, _result: &OptimizationResult<T>) {
+        if let Some(bar) = self.bar.take() {
+            bar.finish();
+        }
+    }
+}
+
+/// Stops a run once the best value has not improved by more than `min_delta` for `patience` steps
+#[derive(Clone, Debug)]
+pub struct EarlyStop<T> where T: Float {
+    pub patience: usize,
+    pub min_delta: T,
+    best: Option<T>,
+    wait: usize
+}
+
+impl<T> EarlyStop<T> where T: Float {
+    pub fn new(patience: usize, min_delta: T) -> Self {
+        Self { patience, min_delta, best: None, wait: 0 }
+    }
+
+    fn observe(&mut self, value: T) {
+        match self.best {
+            Some(best) if best - value <= self.min_delta => self.wait += 1,
+            _ => {
+                self.best = Some(value);
+                self.wait = 0;
+            }
+        }
+    }
+}
+
+impl<T> OptimizationObserver<T> for EarlyStop<T> where T: Float {
+    fn on_start(&mut self, _arg: &Tensor<T>, value: T) {
+        self.best = None;
+        self.wait = 0;
+        self.observe(value);
+    }
+
+    fn on_step(&mut self, _step: usize, _arg: &Tensor<T>, value: T, _grad_norm: T, _step_len: T) {
+        self.observe(value);
+    }
+
+    fn should_stop(&self) -> bool {
+        self.wait >= self.patience
+    }
+}
+
+/// Notifies observers of a run and keeps its best point
+pub(crate) struct Progress<'r, 'a, T> where T: Float {
+    observers: &'r [ObserverRef<'a, T>],
+    arg: Tensor<T>,
+    value: T
+}
+
+impl<'r, 'a, T> Progress<'r, 'a, T> where T: Float {
+    pub(crate) fn start(observers: &'r [ObserverRef<'a, T>], arg: &Tensor<T>, value: T) -> Self {
+        for observer in observers {
+            observer.borrow_mut().on_start(arg, value);
+        }
+        Self { observers, arg: arg.clone(), value }
+    }
+
+    /// Returns `StopReason::Observer` if one of the observers asks to stop
+    pub(crate) fn step(&mut self, step: usize, arg: &Tensor<T>, value: T, grad_norm: T, step_len: T) -> Option<StopReason> {
+        if value < self.value {
+            self.arg = arg.clone();
+            self.value = value;
+        }
+        for observer in self.observers {
+            observer.borrow_mut().on_step(step, arg, value, grad_norm, step_len);
+        }
+        self.observers.iter()
+            .any(|observer| observer.borrow().should_stop())
+            .then_some(StopReason::Observer)
+    }
+
+    pub(crate) fn finish(self, summary: RunSummary, bounds: Option<&Bounds<T>>) -> OptimizationResult<T> {
+        let active_bounds = bounds.map(|bounds| bounds.active(&self.arg)).unwrap_or_default();
+        let result = OptimizationResult { arg: self.arg, value: self.value, summary, active_bounds };
+        for observer in self.observers {
+            observer.borrow_mut().on_finish(&result);
+        }
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::RefCell;
+    use tensor::Vector;
+    use super::{CsvWriter, EarlyStop, History, ObserverRef, Progress};
+    use super::super::{RunSummary, StopReason};
+
+    fn run(observers: &[ObserverRef<f64>], values: &[f64]) -> usize {
+        let mut progress = Progress::start(observers, &Vector::ket(vec![values[0]]), values[0]);
+        let mut steps = 0;
+        for (index, &value) in values.iter().enumerate().skip(1) {
+            steps = index;
+            if progress.step(index, &Vector::ket(vec![value]), value, 1.0, 0.5).is_some() {
+                break;
+            }
+        }
+        let summary = RunSummary { reason: StopReason::StepCount, steps, evaluations: 0, elapsed: Default::default() };
+        progress.finish(summary, None);
+        steps
+    }
+
+    #[test]
+    fn history_keeps_every_point() {
+        let history = RefCell::new(History::new());
+        run(&[&history], &[3.0, 2.0, 2.5, 1.0]);
+        let history = history.borrow();
+        assert_eq!(history.values(), vec![3.0, 2.0, 2.5, 1.0]);
+        assert_eq!(history.best().unwrap().step, 3);
+        assert_eq!(history.entries[0].step_len, 0.0);
+    }
+
+    #[test]
+    fn csv_writer_rows() {
+        let csv = RefCell::new(CsvWriter::new(Vec::new()));
+        run(&[&csv], &[3.0, 2.0]);
+        let csv = csv.into_inner();
+        assert!(csv.error.is_none());
+        let text = String::from_utf8(csv.writer).unwrap();
+        assert_eq!(text, "step,value,grad_norm,step_len,x0\n0,3,0,0,3\n1,2,1,0.5,2\n");
+    }
+
+    #[test]
+    fn early_stop_after_patience() {
+        let early_stop = RefCell::new(EarlyStop::new(2, 0.1));
+        let steps = run(&[&early_stop], &[3.0, 2.0, 1.95, 1.93, 1.0]);
+        assert_eq!(steps, 3);
+    }
+}
@@ -0,0 +1,114 @@
+use num::Float;
+use std::{cell::Cell, fmt::Debug};
+use tensor::Tensor;
+use super::{gradient_with, hessian_with, ActiveBound, DifferenceScheme, RunSummary};
+
+type Objective<'a, T> = Box<dyn Fn(&Tensor<T>) -> T + 'a>;
+type TensorFunction<'a, T> = Box<dyn Fn(&Tensor<T>) -> Tensor<T> + 'a>;
+
+/// Objective to minimize with optional analytic derivatives and a start point.
+/// Derivatives that are not given are approximated numerically by the optimizer.
+/// Every call of the objective through `value` is counted.
+pub struct Problem<'a, T> where T: Float {
+    pub objective: Objective<'a, T>,
+    pub gradient: Option<TensorFunction<'a, T>>,
+    pub hessian: Option<TensorFunction<'a, T>>,
+    pub start_point: Tensor<T>,
+    evaluations: Cell<usize>
+}
+
+impl<'a, T> Problem<'a, T> where T: Float {
+    pub fn new(objective: impl Fn(&Tensor<T>) -> T + 'a, start_point: Tensor<T>) -> Self {
+        Self {
+            objective: Box::new(objective),
+            gradient: None,
+            hessian: None,
+            start_point,
+            evaluations: Cell::new(0)
+        }
+    }
+
+    pub fn with_gradient(mut self, gradient: impl Fn(&Tensor<T>) -> Tensor<T> + 'a) -> Self {
+        self.gradient = Some(Box::new(gradient));
+        self
+    }
+
+    pub fn with_hessian(mut self, hessian: impl Fn(&Tensor<T>) -> Tensor<T> + 'a) -> Self {
+        self.hessian = Some(Box::new(hessian));
+        self
+    }
+
+    pub fn value(&self, arg: &Tensor<T>) -> T {
+        self.evaluations.set(self.evaluations.get() + 1);
+        (self.objective)(arg)
+    }
+
+    /// Analytic gradient if given, numeric one otherwise
+    pub fn gradient(&self, arg: &Tensor<T>, delta: T, scheme: DifferenceScheme) -> Tensor<T> {
+        match &self.gradient {
+            Some(gradient) => gradient(arg),
+            None => gradient_with(&|x: &Tensor<T>| self.value(x), arg, delta, scheme)
+        }
+    }
+
+    /// Analytic Hessian if given, numeric one otherwise
+    pub fn hessian(&self, arg: &Tensor<T>, delta: T, scheme: DifferenceScheme) -> Tensor<T> where T: Debug {
+        match &self.hessian {
+            Some(hessian) => hessian(arg),
+            None => hessian_with(&|x: &Tensor<T>| self.value(x), arg, delta, scheme)
+        }
+    }
+
+    /// Objective evaluations since the last `reset_evaluations`
+    pub fn evaluations(&self) -> usize {
+        self.evaluations.get()
+    }
+
+    pub fn reset_evaluations(&self) {
+        self.evaluations.set(0);
+    }
+}
+
+/// Best point found by an optimizer and how the run ended
+#[derive(Clone, Debug)]
+pub struct OptimizationResult<T> where T: Float {
+    pub arg: Tensor<T>,
+    pub value: T,
+    pub summary: RunSummary,
+    /// Limits the optimum lies on, empty for unconstrained runs
+    pub active_bounds: Vec<ActiveBound>
+}
+
+pub trait Optimizer<T> where T: Float {
+    fn minimize(&mut self, problem: &Problem<T>) -> OptimizationResult<T>;
+
+    /// Whether the method relies on second derivatives, which non-smooth objectives don't have
+    fn is_second_order(&self) -> bool {
+        false
+    }
+
+    /// Whether the steps are kept bounded, e.g. by `GradientDescentRegularization`
+    fn is_regularized(&self) -> bool {
+        false
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use tensor::{Tensor, Vector};
+    use super::super::DifferenceScheme;
+    use super::Problem;
+
+    #[test]
+    fn counts_evaluations() {
+        let problem = Problem::new(|x: &Tensor| x.get_v(0).powi(2), Vector::ket(vec![1.0]));
+        let grad = problem.gradient(&problem.start_point, 1e-4, DifferenceScheme::Central);
+        assert!((grad.get_v(0) - 2.0).abs() < 1e-6);
+        assert_eq!(problem.evaluations(), 2);
+
+        let problem = problem.with_gradient(|x: &Tensor| x * 2.0);
+        problem.reset_evaluations();
+        assert_eq!(problem.gradient(&problem.start_point, 1e-4, DifferenceScheme::Central), Vector::ket(vec![2.0]));
+        assert_eq!(problem.evaluations(), 0);
+    }
+}
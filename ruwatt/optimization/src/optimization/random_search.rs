@@ -0,0 +1,141 @@
+use num::Float;
+use rand::{rngs::StdRng, Rng, SeedableRng};
+use std::{fmt::Debug, time::Instant};
+use tensor::Tensor;
+use super::{ObserverRef, OptimizationResult, Optimizer, Problem, Progress, RunSummary, StopCriteria, StopReason, StopState};
+
+/// Random search: every step evaluates `directions_count` points at distance `step_size`
+/// along random unit directions and moves to the best of them if it improves the value.
+/// Directions come from an rng seeded by `seed`, so a run is reproducible.
+/// `decrement_step` shrinks the distance harmonically, letting the search settle into a local minimum
+/// instead of jumping around it; a step where no direction improves keeps the current point.
+#[derive(Clone)]
+pub struct RandomSearch<'a, T> where T: Float + Debug {
+    pub step_count: usize,
+    pub stop_criteria: StopCriteria<'a, T>,
+    pub directions_count: usize,
+    pub step_size: T,
+    pub decrement_step: bool,
+    pub seed: u64,
+    pub observers: Vec<ObserverRef<'a, T>>,
+    pub verbose: bool
+}
+
+impl<'a, T> Default for RandomSearch<'a, T> where T: Float + Debug {
+    fn default() -> Self {
+        Self {
+            step_count: 100,
+            stop_criteria: StopCriteria::default(),
+            directions_count: 10,
+            step_size: T::one(),
+            decrement_step: true,
+            seed: 0,
+            observers: vec![],
+            verbose: false
+        }
+    }
+}
+
+impl<'a, T> Optimizer<T> for RandomSearch<'a, T> where T: Float + Debug {
+    fn minimize(&mut self, problem: &Problem<T>) -> OptimizationResult<T> {
+        self.log("Random search started");
+        let start = Instant::now();
+
+        let mut rng = StdRng::seed_from_u64(self.seed);
+        let mut arg = problem.start_point.clone();
+        let mut reason = StopReason::StepCount;
+        let mut steps = 0;
+        problem.reset_evaluations();
+        let mut value = problem.value(&arg);
+        let mut progress = Progress::start(&self.observers, &arg, value);
+        for step in 0..self.step_count {
+            let size = if self.decrement_step { self.step_size / T::from(step + 1).unwrap() } else { self.step_size };
+            let candidate = (0..self.directions_count)
+                .map(|_| &arg + &random_direction(&mut rng, &arg).set_length(size))
+                .map(|point| {
+                    let point_value = problem.value(&point);
+                    (point, point_value)
+                })
+                .fold(None, |best: Option<(Tensor<T>, T)>, (point, point_value)| match best {
+                    Some(best) if best.1 <= point_value => Some(best),
+                    _ => Some((point, point_value))
+                });
+            let value_prev = value;
+            let mut arg_change = T::zero();
+            if let Some((point, point_value)) = candidate.filter(|(_, point_value)| *point_value < value) {
+                arg_change = (&point - &arg).length();
+                arg = point;
+                value = point_value;
+            }
+            steps = step + 1;
+            if let Some(stop) = progress.step(steps, &arg, value, T::zero(), arg_change) {
+                reason = stop;
+                break;
+            }
+            let state = StopState {
+                step,
+                arg: &arg,
+                value,
+                value_prev,
+                arg_change,
+                evaluations: problem.evaluations(),
+                elapsed: start.elapsed()
+            };
+            if let Some(stop) = self.stop_criteria.check(&state) {
+                reason = stop;
+                break;
+            }
+        }
+        self.log(&format!("Random search stopped by {:?} after {} steps", reason, steps));
+        self.log(&format!("Random search elapsed in {:?}", start.elapsed()));
+        progress.finish(RunSummary { reason, steps, evaluations: problem.evaluations(), elapsed: start.elapsed() }, None)
+    }
+}
+
+impl<'a, T> RandomSearch<'a, T> where T: Float + Debug {
+    fn log(&self, message: &str) {
+        if self.verbose {
+            println!("{}", message);
+        }
+    }
+}
+
+/// Direction with uniform coordinates in [-1, 1) and the shape of `arg`, normalized by the caller
+fn random_direction<T>(rng: &mut StdRng, arg: &Tensor<T>) -> Tensor<T> where T: Float {
+    let data = arg.data.iter()
+        .map(|_| T::from(rng.gen_range(-1.0..1.0)).unwrap())
+        .collect();
+    Tensor { shape: arg.shape.clone(), data }
+}
+
+#[cfg(test)]
+mod tests {
+    use tensor::{Tensor, Vector, assert_near};
+    use super::{Optimizer, Problem, RandomSearch};
+
+    fn f(x: &Tensor) -> f64 {
+        (3.0 * x.get_v(0)).sin() + 0.3 * x.get_v(0).powi(2)
+    }
+
+    #[test]
+    fn random_search_nonconvex() {
+        let problem = Problem::new(f, Vector::ket(vec![4.5]));
+        let mut optimizator = RandomSearch {
+            step_count: 200,
+            ..Default::default()
+        };
+        let result = optimizator.minimize(&problem);
+        // local minimum next to the start, the global one is near -0.5
+        assert_near!(result.arg, Vector::ket(vec![3.4146]), 1e-2);
+        assert_eq!(result.summary.evaluations, 200 * 10 + 1);
+    }
+
+    #[test]
+    fn random_search_seed_reproducibility() {
+        let g = |x: &Tensor| 100.0 * (x.get_v(1) - x.get_v(0).powi(2)).powi(2) + (x.get_v(0) - 1.0).powi(2);
+        let problem = Problem::new(g, Vector::ket(vec![-2.0, -2.0]));
+        let run = |seed| RandomSearch { seed, step_count: 50, directions_count: 100, ..Default::default() }.minimize(&problem).arg;
+        assert_eq!(run(1), run(1));
+        assert_ne!(run(1), run(2));
+    }
+}
@@ -0,0 +1,233 @@
+use num::Float;
+use rand::{rngs::StdRng, seq::SliceRandom, SeedableRng};
+use std::{fmt::Debug, iter::Sum, time::Instant};
+use tensor::{assert_matrix, Tensor, Vector};
+use super::{gradient_with, DifferenceScheme, ObserverRef, OptimizationResult, Progress, UpdateRule, UpdateState};
+use super::{RunSummary, StopReason};
+
+/// Loss of parameters `w` on a batch of rows `x` with targets `y`
+pub type BatchLoss<'a, T> = &'a dyn Fn(&Tensor<T>, &Tensor<T>, &Tensor<T>) -> T;
+pub type BatchGradient<'a, T> = &'a dyn Fn(&Tensor<T>, &Tensor<T>, &Tensor<T>) -> Tensor<T>;
+
+/// Mini-batch stochastic gradient descent over the rows of `x` and `y`.
+/// Rows are shuffled every epoch with an rng seeded once by `seed`, so runs are reproducible.
+/// Observers see one step per epoch: the loss on all rows, the mean batch gradient norm and the parameters at the epoch end.
+/// The start point is reported with the loss on all rows as well.
+#[derive(Clone)]
+pub struct StochasticGradientDescent<'a, T> where T: Float + Debug {
+    pub loss: BatchLoss<'a, T>,
+    pub gradient: Option<BatchGradient<'a, T>>,
+    pub x: Tensor<T>,
+    pub y: Tensor<T>,
+    pub start_point: Tensor<T>,
+    pub epochs: usize,
+    pub batch_size: usize,
+    pub seed: u64,
+    /// Learning rate of the first epoch, epoch k uses learning_rate / (1 + decay·k)
+    pub learning_rate: T,
+    pub decay: T,
+    pub betta: T,
+    pub update_rule: UpdateRule<T>,
+    pub update_state: UpdateState<T>,
+    pub derivative_delta: T,
+    pub difference_scheme: DifferenceScheme,
+    pub observers: Vec<ObserverRef<'a, T>>,
+    pub verbose: bool
+}
+
+impl<'a, T> Default for StochasticGradientDescent<'a, T> where T: Float + Debug {
+    fn default() -> Self {
+        Self {
+            loss: &|_, _, _| T::zero(),
+            gradient: None,
+            x: Tensor::empty(),
+            y: Tensor::empty(),
+            start_point: Vector::ket(vec![T::zero()]),
+            epochs: 100,
+            batch_size: 32,
+            seed: 0,
+            learning_rate: T::from(0.01).unwrap(),
+            decay: T::zero(),
+            betta: T::zero(),
+            update_rule: UpdateRule::Momentum,
+            update_state: UpdateState::new(),
+            derivative_delta: T::from(0.0001).unwrap(),
+            difference_scheme: DifferenceScheme::Central,
+            observers: vec![],
+            verbose: false
+        }
+    }
+}
+
+impl<'a, T> StochasticGradientDescent<'a, T> where T: Float + Sum + Debug {
+    pub fn run(&mut self) -> OptimizationResult<T> {
+        self.validate();
+        self.log("Stochastic gradient descent started");
+        let start = Instant::now();
+
+        let mut rng = StdRng::seed_from_u64(self.seed);
+        let mut indices: Vec<usize> = (0..self.x.row_count()).collect();
+        let mut arg = self.start_point.clone();
+        let mut reason = StopReason::StepCount;
+        let mut epochs = 0;
+        let mut evaluations = 1;
+        self.update_state.reset();
+        let observers = self.observers.clone();
+        let mut progress = Progress::start(&observers, &arg, (self.loss)(&arg, &self.x, &self.y));
+        for epoch in 0..self.epochs {
+            indices.shuffle(&mut rng);
+            let learning_rate = self.learning_rate / (T::one() + self.decay * T::from(epoch).unwrap());
+            let epoch_start = arg.clone();
+            let mut grad_norm_sum = T::zero();
+            let mut batch_count = 0;
+            for batch in indices.chunks(self.batch_size) {
+                let x = select_rows(&self.x, batch);
+                let y = select_rows(&self.y, batch);
+                let point = self.update_rule.lookahead(&self.update_state, &arg);
+                let grad = match self.gradient {
+                    Some(grad_func) => grad_func(&point, &x, &y),
+                    None => gradient_with(&|w: &Tensor<T>| (self.loss)(w, &x, &y), &point, self.derivative_delta, self.difference_scheme)
+                };
+                grad_norm_sum = grad_norm_sum + grad.length();
+                arg = &arg - &self.update(grad, &arg, learning_rate);
+                batch_count += 1;
+            }
+            let count = T::from(batch_count).unwrap();
+            let value = (self.loss)(&arg, &self.x, &self.y);
+            evaluations += 1;
+            epochs = epoch + 1;
+            self.log(&format!("Epoch {}: loss {:?}", epoch, value));
+            let step_len = (&arg - &epoch_start).length();
+            if let Some(stop) = progress.step(epochs, &arg, value, grad_norm_sum / count, step_len) {
+                reason = stop;
+                break;
+            }
+        }
+        self.log(&format!("Stochastic gradient descent elapsed in {:?}", start.elapsed()));
+        progress.finish(RunSummary { reason, steps: epochs, evaluations, elapsed: start.elapsed() }, None)
+    }
+
+    fn update(&mut self, grad: Tensor<T>, arg: &Tensor<T>, learning_rate: T) -> Tensor<T> {
+        match self.update_rule {
+            UpdateRule::Momentum => {
+                let step = grad * learning_rate;
+                let velocity = if self.update_state.velocity.is_empty() {
+                    step
+                } else {
+                    &(&self.update_state.velocity * self.betta) + &(step * (T::one() - self.betta))
+                };
+                self.update_state.velocity = velocity.clone();
+                velocity
+            },
+            UpdateRule::Nesterov { .. } => self.update_rule.accelerate(&mut self.update_state, grad * learning_rate),
+            _ => self.update_rule.adapt(&mut self.update_state, &grad, arg, learning_rate)
+        }
+    }
+
+    fn validate(&self) {
+        assert_matrix!(self.x);
+        assert_matrix!(self.y);
+        assert_eq!(self.x.row_count(), self.y.row_count(), "Count of x train not correspond to y");
+        assert!(self.batch_size > 0, "Batch size must be positive");
+    }
+
+    fn log(&self, message: &str) {
+        if self.verbose {
+            println!("{}", message);
+        }
+    }
+}
+
+fn select_rows<T>(tensor: &Tensor<T>, rows: &[usize]) -> Tensor<T> where T: Float {
+    let col_count = tensor.col_count();
+    let data = rows.iter()
+        .flat_map(|&row| tensor.data[row * col_count..(row + 1) * col_count].iter().copied())
+        .collect();
+    Tensor { shape: vec![rows.len(), col_count], data }
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::{rngs::StdRng, Rng, SeedableRng};
+    use tensor::{assert_near, dot, Matrix, Tensor, Vector};
+    use std::cell::RefCell;
+    use super::super::{EarlyStop, History, StopReason};
+    use super::{StochasticGradientDescent, UpdateRule};
+
+    fn dataset() -> (Tensor, Tensor) {
+        let mut rng = StdRng::seed_from_u64(7);
+        let x: Vec<Vec<f64>> = (0..200).map(|_| vec![rng.gen_range(-1.0..1.0), rng.gen_range(-1.0..1.0)]).collect();
+        let y = x.iter().map(|row| vec![0.5 + 2.0 * row[0] - 3.0 * row[1]]).collect();
+        (Matrix::new(x), Matrix::new(y))
+    }
+
+    fn mean_squared_error(w: &Tensor, x: &Tensor, y: &Tensor) -> f64 {
+        let weights = Vector::ket(w.data[1..].to_vec());
+        let residual = &(dot(x, &weights) + w.get_v(0)) - y;
+        residual.data.iter().map(|value| value.powi(2)).sum::<f64>() / y.row_count() as f64
+    }
+
+    #[test]
+    fn mini_batch_linear_regression() {
+        let (x, y) = dataset();
+        let history = RefCell::new(History::new());
+        let mut optimizator = StochasticGradientDescent {
+            loss: &mean_squared_error,
+            x: x.clone(),
+            y: y.clone(),
+            start_point: Vector::bra(vec![0.0; 3]),
+            epochs: 50,
+            batch_size: 16,
+            learning_rate: 0.1,
+            observers: vec![&history],
+            ..Default::default()
+        };
+        let result = optimizator.run();
+        assert_eq!(history.borrow().entries.len(), 51);
+        let first = history.borrow().entries[1].value;
+        assert!(result.value < first * 1e-6);
+        assert_eq!(result.value, mean_squared_error(&result.arg, &x, &y));
+        assert_near!(result.arg, Vector::bra(vec![0.5, 2.0, -3.0]), 1e-3);
+    }
+
+    #[test]
+    fn seed_reproducibility() {
+        let (x, y) = dataset();
+        let run = |seed: u64| {
+            let mut optimizator = StochasticGradientDescent {
+                loss: &mean_squared_error,
+                x: x.clone(),
+                y: y.clone(),
+                start_point: Vector::bra(vec![0.0; 3]),
+                epochs: 3,
+                batch_size: 10,
+                seed,
+                update_rule: UpdateRule::adam(),
+                ..Default::default()
+            };
+            optimizator.run().arg
+        };
+        assert_eq!(run(1), run(1));
+        assert_ne!(run(1), run(2));
+    }
+
+    #[test]
+    fn early_stop_epochs() {
+        let (x, y) = dataset();
+        let early_stop = RefCell::new(EarlyStop::new(3, 1e-4));
+        let mut optimizator = StochasticGradientDescent {
+            loss: &mean_squared_error,
+            x,
+            y,
+            start_point: Vector::bra(vec![0.0; 3]),
+            epochs: 1000,
+            batch_size: 16,
+            learning_rate: 0.1,
+            observers: vec![&early_stop],
+            ..Default::default()
+        };
+        let result = optimizator.run();
+        assert_eq!(result.summary.reason, StopReason::Observer);
+        assert!(result.summary.steps < 100, "{}", result.summary.steps);
+    }
+}
@@ -0,0 +1,130 @@
+use num::Float;
+use std::time::Duration;
+use tensor::Tensor;
+
+/// Progress after an optimizer step, as seen by stop criteria
+pub struct StopState<'s, T> where T: Float {
+    pub step: usize,
+    pub arg: &'s Tensor<T>,
+    pub value: T,
+    pub value_prev: T,
+    pub arg_change: T,
+    pub evaluations: usize,
+    pub elapsed: Duration
+}
+
+pub type StopPredicate<'a, T> = &'a dyn Fn(&StopState<T>) -> bool;
+
+/// Conditions that end a run before its step budget is spent, unset ones are ignored
+#[derive(Clone)]
+pub struct StopCriteria<'a, T> where T: Float {
+    /// |∇f| below which the run stops before taking a step
+    pub gradient_norm: Option<T>,
+    /// |f(xₖ) - f(xₖ₋₁)| ≤ tolerance·|f(xₖ₋₁)|
+    pub function_change: Option<T>,
+    /// |xₖ - xₖ₋₁| ≤ tolerance
+    pub arg_change: Option<T>,
+    /// Budget of objective evaluations, numeric derivatives and line searches included
+    pub max_evaluations: Option<usize>,
+    pub timeout: Option<Duration>,
+    pub predicate: Option<StopPredicate<'a, T>>
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum StopReason {
+    StepCount,
+    GradientNorm,
+    FunctionChange,
+    ArgChange,
+    MaxEvaluations,
+    Timeout,
+    Predicate,
+    Regularization,
+    /// One of the run observers asked to stop
+    Observer,
+    /// Constraint violation within the tolerance of a constrained solver
+    ConstraintTolerance,
+    /// A line search found no step satisfying its conditions
+    LineSearch
+}
+
+/// How an optimizer run ended
+#[derive(Debug, Clone, PartialEq)]
+pub struct RunSummary {
+    pub reason: StopReason,
+    pub steps: usize,
+    pub evaluations: usize,
+    pub elapsed: Duration
+}
+
+impl<'a, T> Default for StopCriteria<'a, T> where T: Float {
+    fn default() -> Self {
+        Self {
+            gradient_norm: None,
+            function_change: None,
+            arg_change: None,
+            max_evaluations: None,
+            timeout: None,
+            predicate: None
+        }
+    }
+}
+
+impl<'a, T> StopCriteria<'a, T> where T: Float {
+    pub fn check_gradient(&self, grad: &Tensor<T>) -> Option<StopReason> {
+        match self.gradient_norm {
+            Some(tolerance) if grad.length() <= tolerance => Some(StopReason::GradientNorm),
+            _ => None
+        }
+    }
+
+    pub fn check(&self, state: &StopState<T>) -> Option<StopReason> {
+        if let Some(tolerance) = self.function_change {
+            if (state.value - state.value_prev).abs() <= tolerance * state.value_prev.abs() {
+                return Some(StopReason::FunctionChange);
+            }
+        }
+        if let Some(tolerance) = self.arg_change {
+            if state.arg_change <= tolerance {
+                return Some(StopReason::ArgChange);
+            }
+        }
+        if let Some(max_evaluations) = self.max_evaluations {
+            if state.evaluations >= max_evaluations {
+                return Some(StopReason::MaxEvaluations);
+            }
+        }
+        if let Some(timeout) = self.timeout {
+            if state.elapsed >= timeout {
+                return Some(StopReason::Timeout);
+            }
+        }
+        match self.predicate {
+            Some(predicate) if predicate(state) => Some(StopReason::Predicate),
+            _ => None
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+    use tensor::Vector;
+    use super::{StopCriteria, StopReason, StopState};
+
+    #[test]
+    fn criteria() {
+        let arg = Vector::ket(vec![1.0, 2.0]);
+        let state = StopState { step: 3, arg: &arg, value: 1.0, value_prev: 1.05, arg_change: 0.1, evaluations: 20, elapsed: Duration::from_millis(5) };
+        let stop_at_step = |state: &StopState<f64>| state.step == 3;
+
+        assert_eq!(StopCriteria::default().check(&state), None);
+        assert_eq!(StopCriteria { function_change: Some(0.1), ..Default::default() }.check(&state), Some(StopReason::FunctionChange));
+        assert_eq!(StopCriteria { function_change: Some(0.01), ..Default::default() }.check(&state), None);
+        assert_eq!(StopCriteria { arg_change: Some(0.1), ..Default::default() }.check(&state), Some(StopReason::ArgChange));
+        assert_eq!(StopCriteria { max_evaluations: Some(20), ..Default::default() }.check(&state), Some(StopReason::MaxEvaluations));
+        assert_eq!(StopCriteria { timeout: Some(Duration::from_millis(1)), ..Default::default() }.check(&state), Some(StopReason::Timeout));
+        assert_eq!(StopCriteria { predicate: Some(&stop_at_step), ..Default::default() }.check(&state), Some(StopReason::Predicate));
+        assert_eq!(StopCriteria { gradient_norm: Some(1e-3), ..Default::default() }.check_gradient(&arg), None);
+    }
+}
@@ -1,17 +1,15 @@
 use num::Float;
-use std::iter::Sum;
-use crate::{ assert_ket, assert_square_matrix };
-use crate::tensor::{ dot, Tensor };
+use tensor::{assert_ket, assert_square_matrix, TensorError, Tensor};
 
-pub fn solve_system<T>(a: &Tensor<T>, b: &Tensor<T>) -> Tensor<T> where T: Float + Sum {
+pub fn solve_system<T>(a: &Tensor<T>, b: &Tensor<T>) -> Result<Tensor<T>, TensorError> where T: Float {
     assert_square_matrix!(a);
     assert_ket!(b);
-    dot(&a.inverse(), b)
+    a.solve(b)
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::tensor::{Matrix, Vector};
+    use tensor::{assert_near, Matrix, Vector};
     use super::solve_system;
 
     #[test]
@@ -22,8 +20,8 @@ mod tests {
             vec![3.0, 3.0, 1.0]
         ]);
         let b = Vector::ket(vec![8.0, 10.0, 12.0]);
-        let recieved = solve_system(&a, &b);
+        let recieved = solve_system(&a, &b).unwrap();
         let expected = Vector::ket(vec![1.0, 2.0, 3.0]);
-        assert_eq!(recieved, expected);
+        assert_near!(recieved, expected, 1e-12);
     }
 }
@@ -0,0 +1,203 @@
+use num::Float;
+use tensor::Tensor;
+
+/// How a gradient step is turned into a parameter update.
+/// `Momentum` and `Nesterov` work on top of any `StepSize`; the adaptive rules
+/// (Adam, AdamW, RMSProp, Adagrad) take their learning rate from `OriginGrad` (1),
+/// `Fixed(rate)` or `Decrement(rate)` and scale every parameter separately.
+#[derive(Clone, PartialEq, Debug)]
+pub enum UpdateRule<T> where T: Float {
+    /// Heavy-ball averaging of steps with `betta`
+    Momentum,
+    /// Momentum with the gradient taken at the look-ahead point x - momentum·v
+    Nesterov { momentum: T },
+    Adam { beta1: T, beta2: T, epsilon: T },
+    /// Adam with weight decay decoupled from the gradient
+    AdamW { beta1: T, beta2: T, epsilon: T, weight_decay: T },
+    RMSProp { decay: T, epsilon: T },
+    Adagrad { epsilon: T }
+}
+
+impl<T> UpdateRule<T> where T: Float {
+    pub fn nesterov() -> Self {
+        Self::Nesterov { momentum: T::from(0.9).unwrap() }
+    }
+
+    pub fn adam() -> Self {
+        Self::Adam { beta1: T::from(0.9).unwrap(), beta2: T::from(0.999).unwrap(), epsilon: T::from(1e-8).unwrap() }
+    }
+
+    pub fn adam_w(weight_decay: T) -> Self {
+        Self::AdamW { beta1: T::from(0.9).unwrap(), beta2: T::from(0.999).unwrap(), epsilon: T::from(1e-8).unwrap(), weight_decay }
+    }
+
+    pub fn rms_prop() -> Self {
+        Self::RMSProp { decay: T::from(0.9).unwrap(), epsilon: T::from(1e-8).unwrap() }
+    }
+
+    pub fn adagrad() -> Self {
+        Self::Adagrad { epsilon: T::from(1e-8).unwrap() }
+    }
+
+    pub fn is_adaptive(&self) -> bool {
+        !matches!(self, Self::Momentum | Self::Nesterov { .. })
+    }
+
+    /// Point where the next gradient is evaluated
+    pub fn lookahead(&self, state: &UpdateState<T>, arg: &Tensor<T>) -> Tensor<T> {
+        match self {
+            Self::Nesterov { momentum } if !state.velocity.is_empty() => arg - &(&state.velocity * *momentum),
+            _ => arg.clone()
+        }
+    }
+
+    /// Nesterov velocity v ← momentum·v + step, returned as the update
+    pub fn accelerate(&self, state: &mut UpdateState<T>, step: Tensor<T>) -> Tensor<T> {
+        let velocity = match self {
+            Self::Nesterov { momentum } if !state.velocity.is_empty() => &(&state.velocity * *momentum) + &step,
+            _ => step
+        };
+        state.velocity = velocity.clone();
+        velocity
+    }
+
+    /// Update of an adaptive rule for gradient `grad` at `arg`, to be subtracted from `arg`
+    pub fn adapt(&self, state: &mut UpdateState<T>, grad: &Tensor<T>, arg: &Tensor<T>, learning_rate: T) -> Tensor<T> {
+        state.init(grad);
+        state.step += 1;
+        let one = T::one();
+        let data = match *self {
+            Self::Momentum | Self::Nesterov { .. } => {
+                return grad * learning_rate;
+            },
+            Self::Adam { beta1, beta2, epsilon } | Self::AdamW { beta1, beta2, epsilon, .. } => {
+                let step = state.step as i32;
+                let (correction1, correction2) = (one - beta1.powi(step), one - beta2.powi(step));
+                (0..grad.data.len())
+                    .map(|i| {
+                        let g = grad.data[i];
+                        let m = beta1 * state.velocity.data[i] + (one - beta1) * g;
+                        let v = beta2 * state.second_moment.data[i] + (one - beta2) * g * g;
+                        state.velocity.data[i] = m;
+                        state.second_moment.data[i] = v;
+                        let mut update = (m / correction1) / ((v / correction2).sqrt() + epsilon);
+                        if let Self::AdamW { weight_decay, .. } = *self {
+                            update = update + weight_decay * arg.data[i];
+                        }
+                        learning_rate * update
+                    })
+                    .collect()
+            },
+            Self::RMSProp { decay, epsilon } => {
+                (0..grad.data.len())
+                    .map(|i| {
+                        let g = grad.data[i];
+                        let v = decay * state.second_moment.data[i] + (one - decay) * g * g;
+                        state.second_moment.data[i] = v;
+                        learning_rate * g / (v.sqrt() + epsilon)
+                    })
+                    .collect()
+            },
+            Self::Adagrad { epsilon } => {
+                (0..grad.data.len())
+                    .map(|i| {
+                        let g = grad.data[i];
+                        let v = state.second_moment.data[i] + g * g;
+                        state.second_moment.data[i] = v;
+                        learning_rate * g / (v.sqrt() + epsilon)
+                    })
+                    .collect()
+            }
+        };
+        Tensor { shape: grad.shape.clone(), data }
+    }
+}
+
+/// Per-parameter state of an `UpdateRule`: velocity (Nesterov) or first moment (Adam),
+/// running average or sum of squared gradients, and the count of adaptive steps taken
+#[derive(Clone, Debug)]
+pub struct UpdateState<T> where T: Float {
+    pub velocity: Tensor<T>,
+    pub second_moment: Tensor<T>,
+    pub step: usize
+}
+
+impl<T> UpdateState<T> where T: Float {
+    pub fn new() -> Self {
+        Self { velocity: Tensor::empty(), second_moment: Tensor::empty(), step: 0 }
+    }
+
+    pub fn reset(&mut self) {
+        *self = Self::new();
+    }
+
+    fn init(&mut self, grad: &Tensor<T>) {
+        if self.velocity.shape != grad.shape {
+            self.velocity = Tensor::zeros(grad.shape.clone());
+        }
+        if self.second_moment.shape != grad.shape {
+            self.second_moment = Tensor::zeros(grad.shape.clone());
+        }
+    }
+}
+
+impl<T> Default for UpdateState<T> where T: Float {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use tensor::{assert_near, Vector};
+    use super::{UpdateRule, UpdateState};
+
+    #[test]
+    fn adam_first_step_is_sign() {
+        let mut state = UpdateState::new();
+        let grad = Vector::ket(vec![100.0, -0.01]);
+        let recieved = UpdateRule::adam().adapt(&mut state, &grad, &grad, 0.1);
+        assert_near!(recieved, Vector::ket(vec![0.1, -0.1]), 1e-6);
+        assert_eq!(state.step, 1);
+    }
+
+    #[test]
+    fn adam_w_decays_weights() {
+        let mut state = UpdateState::new();
+        let grad = Vector::ket(vec![0.0, 0.0]);
+        let arg = Vector::ket(vec![2.0, -4.0]);
+        let recieved = UpdateRule::adam_w(0.5).adapt(&mut state, &grad, &arg, 0.1);
+        assert_near!(recieved, Vector::ket(vec![0.1, -0.2]), 1e-12);
+    }
+
+    #[test]
+    fn adagrad_accumulates() {
+        let mut state = UpdateState::new();
+        let grad = Vector::ket(vec![3.0, 4.0]);
+        let rule = UpdateRule::Adagrad { epsilon: 0.0 };
+        rule.adapt(&mut state, &grad, &grad, 1.0);
+        let recieved = rule.adapt(&mut state, &grad, &grad, 1.0);
+        assert_near!(recieved, Vector::ket(vec![1.0 / 2f64.sqrt(); 2]), 1e-12);
+    }
+
+    #[test]
+    fn rms_prop() {
+        let mut state = UpdateState::new();
+        let grad = Vector::ket(vec![2.0]);
+        let rule = UpdateRule::RMSProp { decay: 0.75, epsilon: 0.0 };
+        let recieved = rule.adapt(&mut state, &grad, &grad, 1.0);
+        assert_near!(recieved, Vector::ket(vec![2.0]), 1e-12);
+    }
+
+    #[test]
+    fn nesterov_velocity() {
+        let mut state = UpdateState::new();
+        let rule = UpdateRule::Nesterov { momentum: 0.5 };
+        let arg = Vector::ket(vec![1.0]);
+        assert_eq!(rule.lookahead(&state, &arg), arg);
+        rule.accelerate(&mut state, Vector::ket(vec![0.2]));
+        assert_near!(rule.lookahead(&state, &arg), Vector::ket(vec![0.9]), 1e-12);
+        let recieved = rule.accelerate(&mut state, Vector::ket(vec![0.2]));
+        assert_near!(recieved, Vector::ket(vec![0.3]), 1e-12);
+    }
+}
@@ -0,0 +1,33 @@
+use std::cell::Cell;
+use tensor::{Tensor, Vector, assert_near};
+use optimization::{AugmentedLagrangian, ConstrainedProblem, Lbfgs, Problem};
+
+fn f(vector: &Tensor<f64>) -> f64 {
+    (vector.get_v(0) - 3.0).powi(2) + (vector.get_v(1) - 4.0).powi(2)
+}
+
+fn gradient(vector: &Tensor<f64>) -> Tensor<f64> {
+    Vector::ket(vec![2.0 * (vector.get_v(0) - 3.0), 2.0 * (vector.get_v(1) - 4.0)])
+}
+
+#[test]
+fn unit_ball() {
+    let gradient_calls = Cell::new(0);
+    let counted_gradient = |w: &Tensor<f64>| {
+        gradient_calls.set(gradient_calls.get() + 1);
+        gradient(w)
+    };
+    let problem = Problem::new(f, Vector::ket(vec![0.0, 0.0])).with_gradient(counted_gradient);
+    let constrained = ConstrainedProblem::new(problem)
+        .with_inequality(|w: &Tensor<f64>| w.length().powi(2) - 1.0);
+    let mut solver = AugmentedLagrangian {
+        optimizer: Lbfgs::default(),
+        ..Default::default()
+    };
+    let recieved = solver.minimize(&constrained);
+    assert_near!(recieved.result.arg, Vector::ket(vec![0.6, 0.8]), 1e-5);
+    assert!((recieved.result.value - 16.0).abs() < 1e-4);
+    assert!((recieved.inequality_multipliers[0] - 4.0).abs() < 1e-3, "{:?}", recieved.inequality_multipliers);
+    assert!(gradient_calls.get() > 0);
+    assert_eq!(recieved.result.summary.evaluations, constrained.problem.evaluations());
+}
@@ -1,6 +1,6 @@
 use num::abs;
 use tensor::{Tensor, Vector, assert_near};
-use optimization::GradientDescent;
+use optimization::{GradientDescent, Optimizer, Problem};
 
 fn f(vector: &Tensor<f64>) -> f64 {
     let w0 = vector.get_v(0);
@@ -20,15 +20,9 @@ fn gradient(vector: &Tensor<f64>) -> Tensor<f64> {
 #[test]
 
 fn gradient_descent_analytic() {
-    let mut optimizator = GradientDescent {
-        func: &f,
-        gradient: Some(&gradient),
-        start_point: Vector::ket(vec![3.0, 3.0]),
-        ..Default::default()
-    };
-    optimizator.run();
-    
-    let recieved = optimizator.result.unwrap();
+    let problem = Problem::new(f, Vector::ket(vec![3.0, 3.0])).with_gradient(gradient);
+    let mut optimizator = GradientDescent::default();
+    let recieved = optimizator.minimize(&problem);
     let expected_arg = Vector::ket(vec![0.0, 0.0]);
     let expected_value = 2.0;
     assert_near!(recieved.arg, expected_arg, 0.001);
@@ -43,14 +37,9 @@ fn f8(vector: &Tensor<f64>) -> f64 {
 
 #[test]
 fn gradient_descent_f8() {
-    let mut optimizator = GradientDescent {
-        func: &f8,
-        start_point: Vector::ket(vec![2.0; 8]),
-        ..Default::default()
-    };
-    optimizator.run();
-    
-    let recieved = optimizator.result.unwrap();
+    let problem = Problem::new(f8, Vector::ket(vec![2.0; 8]));
+    let mut optimizator = GradientDescent::default();
+    let recieved = optimizator.minimize(&problem);
     let expected_arg = Vector::ket(vec![0.0; 8]);
     let expected_value = 2.0;
     assert_near!(recieved.arg, expected_arg, 0.001);
@@ -0,0 +1,71 @@
+use num::abs;
+use tensor::{Tensor, Vector, assert_near};
+use optimization::{Bfgs, Bounds, Lbfgs, Optimizer, Problem};
+
+fn f(vector: &Tensor<f64>) -> f64 {
+    let w0 = vector.get_v(0);
+    let w1 = vector.get_v(1);
+    w0.powi(2) + w1.powi(2) + 2.0 * f64::sin(1.5 * (w0 + w1)).powi(2) + 2.0
+}
+
+fn gradient(vector: &Tensor<f64>) -> Tensor<f64> {
+    let w0 = vector.get_v(0);
+    let w1 = vector.get_v(1);
+    let common_teil = 3.0 * f64::sin(3.0 * (w0 + w1));
+    let dw0 = w0 * 2.0 + common_teil;
+    let dw1 = w1 * 2.0 + common_teil;
+    Vector::ket(vec![dw0, dw1])
+}
+
+#[test]
+fn bfgs_analytic() {
+    let problem = Problem::new(f, Vector::ket(vec![0.3, -0.1])).with_gradient(gradient);
+    let mut optimizator = Bfgs::default();
+    let recieved = optimizator.minimize(&problem);
+    assert_near!(recieved.arg, Vector::ket(vec![0.0, 0.0]), 1e-6);
+    assert!(abs(recieved.value - 2.0) < 1e-10);
+}
+
+fn f8(vector: &Tensor<f64>) -> f64 {
+    (0..8)
+        .map(|index| (index + 1) as f64 * vector.get_v(index).powi(2))
+        .sum::<f64>() + 2.0
+}
+
+#[test]
+fn lbfgs_f8() {
+    let problem = Problem::new(f8, Vector::ket(vec![2.0; 8]));
+    let mut optimizator = Lbfgs {
+        history: 3,
+        ..Default::default()
+    };
+    let recieved = optimizator.minimize(&problem);
+    assert_near!(recieved.arg, Vector::ket(vec![0.0; 8]), 1e-5);
+    assert!(abs(recieved.value - 2.0) < 1e-8);
+}
+
+fn rosenbrock(x: &Tensor<f64>) -> f64 {
+    (1.0 - x.get_v(0)).powi(2) + 100.0 * (x.get_v(1) - x.get_v(0).powi(2)).powi(2)
+}
+
+fn grad_rosenbrock(x: &Tensor<f64>) -> Tensor<f64> {
+    Vector::ket(vec![
+        -2.0 * (1.0 - x.get_v(0)) - 400.0 * x.get_v(0) * (x.get_v(1) - x.get_v(0).powi(2)),
+        200.0 * (x.get_v(1) - x.get_v(0).powi(2))
+    ])
+}
+
+#[test]
+fn lbfgs_b_rosenbrock() {
+    let bounds = Bounds::new(Vector::ket(vec![-2.0, -2.0]), Vector::ket(vec![0.5, 2.0]));
+    let problem = Problem::new(rosenbrock, Vector::ket(vec![-1.2, 1.0])).with_gradient(grad_rosenbrock);
+    let mut optimizator = Lbfgs {
+        bounds: Some(bounds.clone()),
+        step_count: 500,
+        ..Default::default()
+    };
+    let recieved = optimizator.minimize(&problem);
+    let projected_grad = bounds.projected_gradient(&recieved.arg, &grad_rosenbrock(&recieved.arg));
+    assert!(projected_grad.length() < 1e-6, "{:?}", recieved.arg);
+    assert_near!(recieved.arg, Vector::ket(vec![0.5, 0.25]), 1e-6);
+}
@@ -5,39 +5,23 @@ use std::iter::Sum;
 use tensor::{Tensor, Vector, assert_matrix, assert_shape};
 
 pub fn mse<T>(y_predict: &Tensor<T>, y_test: &Tensor<T>) -> Tensor<T> where T : Float + Sum {
-    assert_shape!(y_predict, y_test);
-    assert_matrix!(y_predict);
-    let row_count = y_predict.row_count();
-    if row_count == 0 {
-        Tensor::empty()
-    } else {
-        let data: Vec<T> = y_predict.cols().zip(y_test.cols())
-            .map(|(col_model, col_test)| {
-                let difference = col_model - col_test;
-                let summ: T = difference.data.iter().map(|&value| T::powi(value, 2)).sum();
-                summ / T::from(row_count).unwrap()
-            })
-            .collect();
-        Vector::ket(data)
-    }
+    column_mean(y_predict, y_test, |value| value.powi(2))
 }
 
 pub fn mad<T>(y_predict: &Tensor<T>, y_test: &Tensor<T>) -> Tensor<T> where T : Float + Sum {
+    column_mean(y_predict, y_test, T::abs)
+}
+
+/// Mean of `f(predict - test)` for every column, as a ket
+fn column_mean<T>(y_predict: &Tensor<T>, y_test: &Tensor<T>, f: impl Fn(T) -> T) -> Tensor<T> where T : Float {
     assert_shape!(y_predict, y_test);
     assert_matrix!(y_predict);
-    let row_count = y_predict.row_count();
-    if row_count == 0 {
-        Tensor::empty()
-    } else {
-        let data: Vec<T> = y_predict.cols().zip(y_test.cols())
-            .map(|(col_model, col_test)| {
-                let difference = col_model - col_test;
-                let summ: T = difference.data.iter().map(|&value| T::abs(value,)).sum();
-                summ / T::from(row_count).unwrap()
-            })
-            .collect();
-        Vector::ket(data)
+    if y_predict.row_count() == 0 {
+        return Tensor::empty();
     }
+    let mut difference = y_predict - y_test;
+    difference.apply(f);
+    Vector::ket(difference.mean_axis(0, false).data)
 }
 
 pub fn r2_score<T>(y_predict: &Tensor<T>, y_test: &Tensor<T>) -> Tensor<T> where T : Float + Sum{
@@ -47,26 +31,15 @@ pub fn r2_score<T>(y_predict: &Tensor<T>, y_test: &Tensor<T>) -> Tensor<T> where
     if row_count == 0 {
         return Tensor::empty();
     } 
-    let row_means: Vec<T> = y_test.cols()
-        .map(|col_test| col_test.data.iter().cloned().sum::<T>() / T::from(col_test.data.len()).unwrap())
-        .collect();
-
-    let ss_res: Vec<T> = y_test.cols().zip(y_predict.cols())
-        .map(|(col_test, col_predict)| {
-            let col_diff = col_test - col_predict;
-            let summ: T = col_diff.data.iter().map(|&value| value.powi(2)).sum();
-            summ
-        })
-        .collect();
+    let mut residuals = y_test - y_predict;
+    residuals.apply(|value| value.powi(2));
+    let ss_res = residuals.sum_axis(0, true);
 
-    let ss_tot: Vec<T> = y_test.cols().zip(row_means)
-            .map(|(col_test, mean)| {
-                let summ: T = col_test.data.iter().map(|&value| (value - mean).powi(2)).sum();
-                summ
-            })
-            .collect();
+    let mut deviations = y_test - y_test.mean_axis(0, true);
+    deviations.apply(|value| value.powi(2));
+    let ss_tot = deviations.sum_axis(0, true);
 
-    let data = ss_res.iter().zip(ss_tot.iter())
+    let data = ss_res.data.iter().zip(ss_tot.data.iter())
             .map(|(&res, &tot)| T::one() - res / tot)
             .collect();
     Vector::bra(data)
@@ -2,7 +2,7 @@ use num::Float;
 use std::marker::PhantomData;
 use std::iter::Sum;
 
-use tensor::{Tensor, Vector, assert_matrix};
+use tensor::{Tensor, assert_matrix};
 
 pub struct Statistics<T = f64> {
     _marker: PhantomData<T>
@@ -11,26 +11,16 @@ pub struct Statistics<T = f64> {
 impl<T> Statistics<T> where T: Float + Sum {
     pub fn mean(matrix: &Tensor<T>) -> Tensor<T> {
         assert_matrix!(matrix);
-        let row_count = T::from(matrix.row_count()).unwrap();
-        let data = matrix.cols()
-            .map(|col| col.data.iter().cloned().sum::<T>() / row_count)
-            .collect();
-        Vector::bra(data)
+        matrix.mean_axis(0, true)
     }
 
     pub fn std_dev(matrix: &Tensor<T>) -> Tensor<T> {
         let means = Self::mean(&matrix);
-        let row_count = T::from(matrix.row_count()).unwrap();
-        let data = matrix.cols()
-            .zip(means.data)
-            .map(|(col, mean)| {
-                let variance = col.data.iter().cloned()
-                    .map(|value| T::powi(value - mean, 2))
-                    .sum::<T>() / row_count;  //TODO  row_count - 1 ?
-                variance.sqrt()
-            })
-            .collect();
-        Vector::bra(data)
+        let mut deviations = matrix - means;
+        deviations.apply(|value| value.powi(2));
+        let mut variance = deviations.mean_axis(0, true);  //TODO  row_count - 1 ?
+        variance.apply(T::sqrt);
+        variance
     }
 
     pub fn normalize(matrix: &Tensor<T>) -> Tensor<T> {
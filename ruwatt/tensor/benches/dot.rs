@@ -0,0 +1,41 @@
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion};
+use tensor::{dot, IndexTools, Tensor};
+
+/// The triple loop `dot` used before the blocked kernel, kept as the baseline
+fn dot_naive(a: &Tensor, b: &Tensor) -> Tensor {
+    let row_count = a.row_count();
+    let col_count = b.col_count();
+    let mut data = vec![0.0; row_count * col_count];
+    for row_index in 0..row_count {
+        let row = IndexTools::<f64>::row(row_index, &a.shape, &a.data).unwrap();
+        for col_index in 0..col_count {
+            let col = IndexTools::<f64>::col(col_index, &b.shape, &b.data).unwrap();
+            data[row_index * col_count + col_index] = row.iter().zip(col.iter()).map(|(&a, &b)| a * b).sum();
+        }
+    }
+    Tensor { shape: vec![row_count, col_count], data }
+}
+
+fn matrix(size: usize) -> Tensor {
+    let data = (0..size * size).map(|i| ((i * 7919 % 1000) as f64) / 500.0 - 1.0).collect();
+    Tensor { shape: vec![size, size], data }
+}
+
+fn bench_dot(c: &mut Criterion) {
+    let mut group = c.benchmark_group("dot");
+    group.sample_size(10);
+    for size in [64, 256, 512] {
+        let a = matrix(size);
+        let b = matrix(size);
+        group.bench_with_input(BenchmarkId::new("naive", size), &size, |bencher, _| {
+            bencher.iter(|| dot_naive(black_box(&a), black_box(&b)))
+        });
+        group.bench_with_input(BenchmarkId::new("blocked", size), &size, |bencher, _| {
+            bencher.iter(|| dot(black_box(&a), black_box(&b)))
+        });
+    }
+    group.finish();
+}
+
+criterion_group!(benches, bench_dot);
+criterion_main!(benches);
@@ -6,9 +6,11 @@ pub mod operators;
 pub mod scalar;
 pub mod tensor;
 pub mod vector;
+pub mod view;
 
 pub use matrix::*;
 pub use operators::*;
 pub use scalar::*;
 pub use tensor::*;
 pub use vector::*;
+pub use view::*;
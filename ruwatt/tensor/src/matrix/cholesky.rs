@@ -0,0 +1,116 @@
+use num::Float;
+use crate::Tensor;
+use crate::TensorError;
+use super::triangular::{solve_lower, solve_upper};
+
+/// A = L·Lᵀ for symmetric positive definite A.
+/// Only the lower triangle of A is read.
+#[derive(Debug, Clone)]
+pub struct CholeskyDecomposition<T> where T: Float {
+    pub l: Tensor<T>
+}
+
+impl<T> CholeskyDecomposition<T> where T: Float {
+    pub fn new(matrix: &Tensor<T>) -> Result<Self, TensorError> {
+        matrix.check_square()?;
+        let size = matrix.row_count();
+        let mut l = vec![T::zero(); size * size];
+        for row in 0..size {
+            for col in 0..=row {
+                let summ = (0..col).fold(T::zero(), |sum, k| sum + l[row * size + k] * l[col * size + k]);
+                let value = matrix.data[row * size + col] - summ;
+                if row == col {
+                    if value <= T::zero() || !value.is_finite() {
+                        return Err(TensorError::NotPositiveDefinite);
+                    }
+                    l[row * size + col] = value.sqrt();
+                } else {
+                    l[row * size + col] = value / l[col * size + col];
+                }
+            }
+        }
+        Ok(Self { l: Tensor { shape: vec![size, size], data: l } })
+    }
+
+    /// Solves A·X = B for every column of B
+    pub fn solve(&self, b: &Tensor<T>) -> Result<Tensor<T>, TensorError> {
+        b.check_matrix()?;
+        if self.l.row_count() != b.row_count() {
+            return Err(TensorError::ShapeMismatch { operation: "solve", left: self.l.shape.clone(), right: b.shape.clone() });
+        }
+        let y = solve_lower(&self.l, b, false);
+        Ok(solve_upper(&self.l.tr(), &y))
+    }
+
+    pub fn det(&self) -> T {
+        let size = self.l.row_count();
+        (0..size).fold(T::one(), |det, i| det * self.l.data[i * size + i].powi(2))
+    }
+}
+
+impl<T> Tensor<T> where T: Float {
+    pub fn cholesky(&self) -> Result<CholeskyDecomposition<T>, TensorError> {
+        CholeskyDecomposition::new(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{dot, Matrix, Tensor, Vector};
+    use super::TensorError;
+
+    fn spd() -> Tensor {
+        Matrix::new(vec![
+            vec![  4.0,  12.0, -16.0],
+            vec![ 12.0,  37.0, -43.0],
+            vec![-16.0, -43.0,  98.0]
+        ])
+    }
+
+    #[test]
+    fn factorization() {
+        let recieved = spd().cholesky().unwrap().l;
+        let expected = Matrix::new(vec![
+            vec![ 2.0, 0.0, 0.0],
+            vec![ 6.0, 1.0, 0.0],
+            vec![-8.0, 5.0, 3.0]
+        ]);
+        assert_eq!(recieved, expected);
+    }
+
+    #[test]
+    fn solve() {
+        let matrix = spd();
+        let x = Vector::ket(vec![1.0, -2.0, 0.5]);
+        let b = dot(&matrix, &x);
+        let recieved = matrix.cholesky().unwrap().solve(&b).unwrap();
+        assert_near!(recieved, x, 1e-10);
+    }
+
+    #[test]
+    fn det() {
+        let recieved = spd().cholesky().unwrap().det();
+        assert!(f64::abs(recieved - 36.0) < 1e-10);
+    }
+
+    #[test]
+    fn not_positive_definite() {
+        let matrix = Matrix::square(vec![1.0, 2.0, 2.0, 1.0]);
+        assert!(matches!(matrix.cholesky(), Err(TensorError::NotPositiveDefinite)));
+    }
+
+    #[test]
+    fn not_square() {
+        let matrix = Matrix::new(vec![vec![1.0, 2.0]]);
+        assert_eq!(matrix.cholesky().unwrap_err(), TensorError::NotSquareMatrix { shape: vec![1, 2] });
+    }
+
+    #[test]
+    fn solve_mismatch() {
+        let cholesky = spd().cholesky().unwrap();
+        let recieved = cholesky.solve(&Vector::ket(vec![1.0, 2.0]));
+        assert_eq!(recieved.unwrap_err(), TensorError::ShapeMismatch { operation: "solve", left: vec![3, 3], right: vec![2, 1] });
+        let recieved = cholesky.solve(&Vector::ket(vec![1.0, 2.0, 3.0]).reshape(&[3]));
+        assert_eq!(recieved.unwrap_err(), TensorError::WrongRank { expected: 2, shape: vec![3] });
+    }
+}
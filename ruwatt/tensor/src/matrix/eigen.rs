@@ -0,0 +1,180 @@
+use num::Float;
+use std::cmp::Ordering;
+use crate::{Tensor, Vector};
+
+const MAX_SWEEPS: usize = 100;
+
+/// A = V·diag(values)·Vᵀ for symmetric A.
+/// Eigenvalues are sorted descending, eigenvectors are the columns of `vectors`.
+#[derive(Debug, Clone)]
+pub struct SymmetricEigen<T> where T: Float {
+    pub values: Tensor<T>,
+    pub vectors: Tensor<T>
+}
+
+impl<T> SymmetricEigen<T> where T: Float {
+    /// Cyclic Jacobi rotations, only the upper triangle of A is read
+    pub fn new(matrix: &Tensor<T>) -> Self {
+        assert_square_matrix!(matrix);
+        let size = matrix.row_count();
+        let mut a = matrix.data.clone();
+        for row in 0..size {
+            for col in 0..row {
+                a[row * size + col] = a[col * size + row];
+            }
+        }
+        let mut v = vec![T::zero(); size * size];
+        for i in 0..size {
+            v[i * size + i] = T::one();
+        }
+
+        let norm = a.iter().fold(T::zero(), |sum, &value| sum + value * value).sqrt();
+        let tolerance = T::epsilon() * norm;
+        for _ in 0..MAX_SWEEPS {
+            let off_diagonal = (0..size)
+                .flat_map(|p| (p + 1..size).map(move |q| (p, q)))
+                .fold(T::zero(), |sum, (p, q)| sum + a[p * size + q].powi(2))
+                .sqrt();
+            if off_diagonal <= tolerance {
+                break;
+            }
+            for p in 0..size {
+                for q in p + 1..size {
+                    Self::rotate(&mut a, &mut v, size, p, q);
+                }
+            }
+        }
+
+        let mut order: Vec<usize> = (0..size).collect();
+        order.sort_by(|&i, &j| descending(a[i * size + i], a[j * size + j]));
+        let values = order.iter().map(|&i| a[i * size + i]).collect();
+        let mut vectors = vec![T::zero(); size * size];
+        for (col, &source) in order.iter().enumerate() {
+            let sign = canonical_sign((0..size).map(|row| v[row * size + source]));
+            for row in 0..size {
+                vectors[row * size + col] = sign * v[row * size + source];
+            }
+        }
+        Self {
+            values: Vector::ket(values),
+            vectors: Tensor { shape: vec![size, size], data: vectors }
+        }
+    }
+
+    fn rotate(a: &mut [T], v: &mut [T], size: usize, p: usize, q: usize) {
+        let apq = a[p * size + q];
+        if apq == T::zero() {
+            return;
+        }
+        let two = T::from(2).unwrap();
+        let theta = (a[q * size + q] - a[p * size + p]) / (two * apq);
+        let t = theta.signum() / (theta.abs() + (T::one() + theta * theta).sqrt());
+        let c = T::one() / (T::one() + t * t).sqrt();
+        let s = t * c;
+        for k in 0..size {
+            let akp = a[k * size + p];
+            let akq = a[k * size + q];
+            a[k * size + p] = c * akp - s * akq;
+            a[k * size + q] = s * akp + c * akq;
+        }
+        for k in 0..size {
+            let apk = a[p * size + k];
+            let aqk = a[q * size + k];
+            a[p * size + k] = c * apk - s * aqk;
+            a[q * size + k] = s * apk + c * aqk;
+        }
+        for k in 0..size {
+            let vkp = v[k * size + p];
+            let vkq = v[k * size + q];
+            v[k * size + p] = c * vkp - s * vkq;
+            v[k * size + q] = s * vkp + c * vkq;
+        }
+    }
+}
+
+/// Sign that makes the first of the largest (by abs) components positive
+pub(crate) fn canonical_sign<T>(values: impl Iterator<Item = T> + Clone) -> T where T: Float {
+    let max = values.clone().fold(T::zero(), |max, value| max.max(value.abs()));
+    let threshold = max * (T::one() - T::epsilon().sqrt());
+    match values.into_iter().find(|value| value.abs() >= threshold) {
+        Some(value) if value < T::zero() => -T::one(),
+        _ => T::one()
+    }
+}
+
+/// Descending order that is total for NaN: NaN values go last
+pub(crate) fn descending<T>(a: T, b: T) -> Ordering where T: Float {
+    match (a.is_nan(), b.is_nan()) {
+        (true, true) => Ordering::Equal,
+        (true, false) => Ordering::Greater,
+        (false, true) => Ordering::Less,
+        (false, false) => b.partial_cmp(&a).unwrap()
+    }
+}
+
+impl<T> Tensor<T> where T: Float {
+    pub fn eig_symmetric(&self) -> SymmetricEigen<T> {
+        SymmetricEigen::new(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{dot, Matrix, Tensor, Vector};
+
+    fn matrix3x3() -> Tensor {
+        Matrix::new(vec![
+            vec![4.0, 1.0, 2.0],
+            vec![1.0, 3.0, 0.0],
+            vec![2.0, 0.0, 5.0]
+        ])
+    }
+
+    #[test]
+    fn known_2x2() {
+        let matrix = Matrix::square(vec![2.0, 1.0, 1.0, 2.0]);
+        let eigen = matrix.eig_symmetric();
+        let half = f64::sqrt(0.5);
+        assert_near!(eigen.values, Vector::ket(vec![3.0, 1.0]), 1e-12);
+        assert_near!(eigen.vectors, Matrix::square(vec![half, half, half, -half]), 1e-12);
+    }
+
+    #[test]
+    fn decomposition() {
+        let matrix = matrix3x3();
+        let eigen = matrix.eig_symmetric();
+        let mut lambda = Tensor::zeros(vec![3, 3]);
+        for i in 0..3 {
+            lambda.set(vec![i, i], eigen.values.get_v(i));
+        }
+        let recieved = dot(&dot(&eigen.vectors, &lambda), &eigen.vectors.tr());
+        assert_near!(recieved, matrix, 1e-10);
+        assert_near!(dot(&eigen.vectors.tr(), &eigen.vectors), Matrix::<f64>::ident(3), 1e-10);
+    }
+
+    #[test]
+    fn sorted_and_signed() {
+        let eigen = matrix3x3().eig_symmetric();
+        let values = &eigen.values.data;
+        assert!(values.windows(2).all(|pair| pair[0] >= pair[1]));
+        for col in eigen.vectors.cols() {
+            let max = col.data.iter().cloned().fold(0.0, |max: f64, x| if x.abs() > max.abs() { x } else { max });
+            assert!(max > 0.0);
+        }
+    }
+
+    #[test]
+    fn diagonal() {
+        let matrix = Matrix::square(vec![1.0, 0.0, 0.0, 0.0, 3.0, 0.0, 0.0, 0.0, 2.0]);
+        let eigen = matrix.eig_symmetric();
+        assert_eq!(eigen.values, Vector::ket(vec![3.0, 2.0, 1.0]));
+        assert_eq!(eigen.vectors, Matrix::square(vec![0.0, 0.0, 1.0, 1.0, 0.0, 0.0, 0.0, 1.0, 0.0]));
+    }
+
+    #[test]
+    fn nan_does_not_panic() {
+        let matrix = Matrix::square(vec![f64::NAN, 0.0, 0.0, 1.0]);
+        let eigen = matrix.eig_symmetric();
+        assert!(eigen.values.get_v(1).is_nan());
+    }
+}
@@ -0,0 +1,82 @@
+use num::Float;
+use crate::{dot_view, Tensor};
+use crate::TensorError;
+use super::triangular::solve_upper;
+
+/// Minimizes ||A·X - B|| for every column of B via QR, without forming AᵀA.
+/// A needs at least as many rows as columns, underdetermined systems are a shape mismatch.
+pub fn lstsq<T>(a: &Tensor<T>, b: &Tensor<T>) -> Result<Tensor<T>, TensorError> where T: Float + Send + Sync {
+    if let Some(tensor) = [a, b].into_iter().find(|tensor| !tensor.is_matrix()) {
+        return Err(TensorError::WrongRank { expected: 2, shape: tensor.shape.clone() });
+    }
+    if a.row_count() != b.row_count() || a.row_count() < a.col_count() {
+        return Err(TensorError::ShapeMismatch { operation: "lstsq", left: a.shape.clone(), right: b.shape.clone() });
+    }
+
+    let qr = a.qr();
+    let size = qr.r.row_count();
+    let max_diagonal = (0..size).fold(T::zero(), |max, i| max.max(qr.r.data[i * size + i]));
+    let tolerance = T::from(a.row_count().max(size)).unwrap() * T::epsilon() * max_diagonal;
+    if (0..size).any(|i| qr.r.data[i * size + i] <= tolerance) {
+        return Err(TensorError::SingularMatrix);
+    }
+    let qtb = dot_view(&qr.q.view().tr(), &b.view());
+    Ok(solve_upper(&qr.r, &qtb))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Matrix, Vector};
+    use super::{lstsq, TensorError};
+
+    #[test]
+    fn line_fit() {
+        let a = Matrix::new(vec![
+            vec![1.0, 0.0],
+            vec![1.0, 1.0],
+            vec![1.0, 2.0],
+            vec![1.0, 3.0]
+        ]);
+        let b = Vector::ket(vec![1.0, 3.0, 5.0, 7.0]);
+        let recieved = lstsq(&a, &b).unwrap();
+        assert_near!(recieved, Vector::ket(vec![1.0, 2.0]), 1e-12);
+    }
+
+    #[test]
+    fn overdetermined() {
+        let a = Matrix::new(vec![
+            vec![1.0, 1.0],
+            vec![1.0, 2.0],
+            vec![1.0, 3.0]
+        ]);
+        let b = Matrix::new(vec![
+            vec![1.0, 3.0],
+            vec![2.0, 2.0],
+            vec![2.0, 1.0]
+        ]);
+        let recieved = lstsq(&a, &b).unwrap();
+        let expected = Matrix::new(vec![
+            vec![2.0 / 3.0, 4.0],
+            vec![0.5,      -1.0]
+        ]);
+        assert_near!(recieved, expected, 1e-12);
+    }
+
+    #[test]
+    fn rank_deficient() {
+        let a = Matrix::new(vec![
+            vec![1.0, 2.0],
+            vec![2.0, 4.0],
+            vec![3.0, 6.0]
+        ]);
+        let b = Vector::ket(vec![1.0, 2.0, 3.0]);
+        assert_eq!(lstsq(&a, &b), Err(TensorError::SingularMatrix));
+    }
+
+    #[test]
+    fn underdetermined() {
+        let a = Matrix::new(vec![vec![1.0, 2.0, 3.0]]);
+        let b = Vector::ket(vec![1.0]);
+        assert_eq!(lstsq(&a, &b), Err(TensorError::ShapeMismatch { operation: "lstsq", left: vec![1, 3], right: vec![1, 1] }));
+    }
+}
@@ -0,0 +1,201 @@
+use num::Float;
+use crate::{Matrix, Tensor};
+use crate::TensorError;
+use super::triangular::{solve_lower, solve_upper};
+
+/// PA = LU factorization with partial pivoting.
+/// L (unit diagonal, not stored) and U share the `lu` matrix.
+#[derive(Debug, Clone)]
+pub struct LuDecomposition<T> where T: Float {
+    pub lu: Tensor<T>,
+    pub pivots: Vec<usize>,
+    pub sign: T,
+    pub tolerance: T
+}
+
+impl<T> LuDecomposition<T> where T: Float {
+    pub fn new(matrix: &Tensor<T>) -> Self {
+        assert_square_matrix!(matrix);
+        let size = matrix.row_count();
+        let mut data = matrix.data.clone();
+        let mut pivots: Vec<usize> = (0..size).collect();
+        let mut sign = T::one();
+
+        for k in 0..size {
+            let pivot_row = (k..size).fold(k, |max_row, row| {
+                if data[row * size + k].abs() > data[max_row * size + k].abs() { row } else { max_row }
+            });
+            if pivot_row != k {
+                for col in 0..size {
+                    data.swap(k * size + col, pivot_row * size + col);
+                }
+                pivots.swap(k, pivot_row);
+                sign = -sign;
+            }
+
+            let pivot = data[k * size + k];
+            if pivot == T::zero() {
+                continue;
+            }
+            for row in k + 1..size {
+                let factor = data[row * size + k] / pivot;
+                data[row * size + k] = factor;
+                for col in k + 1..size {
+                    data[row * size + col] = data[row * size + col] - factor * data[k * size + col];
+                }
+            }
+        }
+
+        let max_abs = matrix.data.iter().fold(T::zero(), |max, &value| max.max(value.abs()));
+        let tolerance = T::from(size).unwrap() * T::epsilon() * max_abs;
+        Self {
+            lu: Tensor { shape: vec![size, size], data },
+            pivots,
+            sign,
+            tolerance
+        }
+    }
+
+    pub fn size(&self) -> usize {
+        self.lu.row_count()
+    }
+
+    pub fn is_singular(&self) -> bool {
+        let size = self.size();
+        (0..size).any(|i| self.lu.data[i * size + i].abs() <= self.tolerance)
+    }
+
+    /// Product of the pivots with the permutation sign, singularity is decided by `solve` and `inverse`
+    pub fn det(&self) -> T {
+        let size = self.size();
+        (0..size).fold(self.sign, |det, i| det * self.lu.data[i * size + i])
+    }
+
+    pub fn l(&self) -> Tensor<T> {
+        let size = self.size();
+        let mut result = Matrix::ident(size);
+        for row in 0..size {
+            for col in 0..row {
+                result.data[row * size + col] = self.lu.data[row * size + col];
+            }
+        }
+        result
+    }
+
+    pub fn u(&self) -> Tensor<T> {
+        let size = self.size();
+        let mut result = Tensor::zeros(vec![size, size]);
+        for row in 0..size {
+            for col in row..size {
+                result.data[row * size + col] = self.lu.data[row * size + col];
+            }
+        }
+        result
+    }
+
+    pub fn p(&self) -> Tensor<T> {
+        let size = self.size();
+        let mut result = Tensor::zeros(vec![size, size]);
+        for (row, &pivot) in self.pivots.iter().enumerate() {
+            result.data[row * size + pivot] = T::one();
+        }
+        result
+    }
+
+    /// Solves A·X = B for every column of B
+    pub fn solve(&self, b: &Tensor<T>) -> Result<Tensor<T>, TensorError> {
+        if !b.is_matrix() {
+            return Err(TensorError::WrongRank { expected: 2, shape: b.shape.clone() });
+        }
+        if self.size() != b.row_count() {
+            return Err(TensorError::ShapeMismatch { operation: "solve", left: self.lu.shape.clone(), right: b.shape.clone() });
+        }
+        if self.is_singular() {
+            return Err(TensorError::SingularMatrix);
+        }
+
+        let rhs_count = b.col_count();
+        let data = self.pivots.iter()
+            .flat_map(|&pivot| b.data[pivot * rhs_count..(pivot + 1) * rhs_count].to_vec())
+            .collect();
+        let permuted = Tensor { shape: b.shape.clone(), data };
+        let y = solve_lower(&self.lu, &permuted, true);
+        Ok(solve_upper(&self.lu, &y))
+    }
+
+    pub fn inverse(&self) -> Result<Tensor<T>, TensorError> {
+        self.solve(&Matrix::ident(self.size()))
+    }
+}
+
+impl<T> Tensor<T> where T: Float {
+    pub fn lu(&self) -> LuDecomposition<T> {
+        LuDecomposition::new(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{dot, Matrix, Tensor, Vector};
+    use super::{TensorError, LuDecomposition};
+
+    fn matrix3x3() -> Tensor {
+        Matrix::new(vec![
+            vec![1.0, 2.0, 1.0],
+            vec![2.0, 1.0, 2.0],
+            vec![3.0, 3.0, 1.0]
+        ])
+    }
+
+    #[test]
+    fn factorization() {
+        let matrix = matrix3x3();
+        let lu = LuDecomposition::new(&matrix);
+        let recieved = dot(&lu.l(), &lu.u());
+        let expected = dot(&lu.p(), &matrix);
+        assert_near!(recieved, expected, 1e-12);
+        assert_eq!(lu.pivots[0], 2);
+    }
+
+    #[test]
+    fn det() {
+        let lu = matrix3x3().lu();
+        assert!(f64::abs(lu.det() - 6.0) < 1e-12);
+    }
+
+    #[test]
+    fn det_badly_scaled() {
+        let matrix = Matrix::square(vec![1e-20, 0.0, 0.0, 1e20]);
+        assert_eq!(matrix.lu().det(), 1.0);
+    }
+
+    #[test]
+    fn solve() {
+        let b = Vector::ket(vec![8.0, 10.0, 12.0]);
+        let recieved = matrix3x3().lu().solve(&b).unwrap();
+        let expected = Vector::ket(vec![1.0, 2.0, 3.0]);
+        assert_near!(recieved, expected, 1e-12);
+    }
+
+    #[test]
+    fn solve_singular() {
+        let matrix = Matrix::square((1..=9).map(|x| x as f64).collect());
+        let b = Vector::ket(vec![1.0, 2.0, 3.0]);
+        let recieved = matrix.lu().solve(&b);
+        assert_eq!(recieved, Err(TensorError::SingularMatrix));
+    }
+
+    #[test]
+    fn inverse_large() {
+        let size = 60;
+        let mut matrix = Tensor::zeros(vec![size, size]);
+        for i in 0..size {
+            for j in 0..size {
+                let value = 1.0 / (i + j + 1) as f64 + if i == j { 2.0 } else { 0.0 };
+                matrix.set(vec![i, j], value);
+            }
+        }
+        let inverse = matrix.lu().inverse().unwrap();
+        assert_near!(dot(&matrix, &inverse), Matrix::<f64>::ident(size), 1e-10);
+    }
+}
@@ -1,7 +1,7 @@
 use num::Float;
 use std::marker::PhantomData;
 use crate::{assert_bra, assert_ket, assert_matrix};
-use crate::{Tensor, IndexTools, IndexError, Vector};
+use crate::{Tensor, IndexTools, TensorError, Vector};
 
 pub struct Matrix<T = f64> {
     _marker: PhantomData<T>
@@ -35,27 +35,19 @@ impl<T> Matrix<T> where T: Float {
     }
 
     pub fn concat_h(a: Tensor<T>, b: Tensor<T>) -> Tensor<T> {
-        assert_eq!(
-            a.row_count(), 
-            b.row_count(), 
-            "Incompatible shape {:?} vs {:?}", a.shape, b.shape
-        );
-        let mut result = Tensor::<T>::empty();
-        result.assign(a);
-        b.cols().for_each(|col| result.append_col(col));
-        result
+        Tensor::concat(&[a, b], 1)
+    }
+
+    pub fn try_concat_h(a: Tensor<T>, b: Tensor<T>) -> Result<Tensor<T>, TensorError> {
+        Tensor::try_concat(&[a, b], 1)
     }
 
     pub fn concat_v(a: Tensor<T>, b: Tensor<T>) -> Tensor<T> {
-        assert_eq!(
-            a.col_count(), 
-            b.col_count(), 
-            "Incompatible shape {:?} vs {:?}", a.shape, b.shape
-        );
-        let mut result = Tensor::<T>::empty();
-        result.assign(a);
-        b.rows().for_each(|row| result.append_row(row));
-        result
+        Tensor::concat(&[a, b], 0)
+    }
+
+    pub fn try_concat_v(a: Tensor<T>, b: Tensor<T>) -> Result<Tensor<T>, TensorError> {
+        Tensor::try_concat(&[a, b], 0)
     }
 }
 
@@ -101,19 +93,23 @@ impl<T> Tensor<T> where T: Float {
         IndexTools::<T>::col_count(&self.shape)
     }
 
-    pub fn row(&self, index: usize) -> Result<Self, IndexError> {
+    pub fn row(&self, index: usize) -> Result<Self, TensorError> {
         assert_matrix!(self);
         let data = IndexTools::<T>::row(index, &self.shape, &self.data)?;
         Ok(Vector::<T>::bra(data))
     }
 
-    pub fn col(&self, index: usize) -> Result<Self, IndexError> {
+    pub fn col(&self, index: usize) -> Result<Self, TensorError> {
         assert_matrix!(self);
         let data = IndexTools::<T>::col(index, &self.shape, &self.data)?;
         Ok(Vector::<T>::ket(data))
     }
 
-    pub fn get_cols(&self, indices: Vec<usize>) -> Result<Self, IndexError> {
+    pub fn get_cols(&self, indices: Vec<usize>) -> Result<Self, TensorError> {
+        let col_count = self.col_count();
+        if let Some(&index) = indices.iter().find(|&&index| index >= col_count) {
+            return Err(TensorError::IndexOutOfRange { index, size: col_count });
+        }
         let mut result = Tensor::<T>::empty();
         self.cols().enumerate()
             .filter(|(index, _)| indices.contains(index))
@@ -122,37 +118,58 @@ impl<T> Tensor<T> where T: Float {
     }
 
     pub fn append_row(&mut self, row: Tensor<T>) {
-        assert_bra!(row);
+        self.try_append_row(row).unwrap_or_else(|error| panic!("{error}"))
+    }
+
+    pub fn try_append_row(&mut self, row: Tensor<T>) -> Result<(), TensorError> {
+        if !row.is_bra() {
+            return Err(TensorError::ShapeMismatch { operation: "append_row", left: self.shape.clone(), right: row.shape });
+        }
         if self.is_empty() {
             self.assign(row);
-            return;
+            return Ok(());
+        }
+        if !self.is_matrix() {
+            return Err(TensorError::WrongRank { expected: 2, shape: self.shape.clone() });
+        }
+        if self.col_count() != row.dim() {
+            return Err(TensorError::ShapeMismatch { operation: "append_row", left: self.shape.clone(), right: row.shape });
         }
-        assert_matrix!(self);
-        assert_eq!(self.col_count(), row.dim(), "Size mismatch");
         self.shape[0] = self.shape[0] + 1;
         self.data.extend(row.data);
+        Ok(())
     }
 
     pub fn append_col(&mut self, col: Tensor<T>) {
-        assert_ket!(col);
+        self.try_append_col(col).unwrap_or_else(|error| panic!("{error}"))
+    }
+
+    pub fn try_append_col(&mut self, col: Tensor<T>) -> Result<(), TensorError> {
+        if !col.is_ket() {
+            return Err(TensorError::ShapeMismatch { operation: "append_col", left: self.shape.clone(), right: col.shape });
+        }
         if self.is_empty() {
             self.assign(col);
-            return;
+            return Ok(());
+        }
+        if !self.is_matrix() {
+            return Err(TensorError::WrongRank { expected: 2, shape: self.shape.clone() });
+        }
+        if self.row_count() != col.dim() {
+            return Err(TensorError::ShapeMismatch { operation: "append_col", left: self.shape.clone(), right: col.shape });
         }
-
-        assert_matrix!(self);
-        assert_eq!(self.row_count(), col.dim(), "Size mismatch");
         let col_count = self.col_count();
         self.shape[1] = col_count + 1;
         for (i, value) in col.data.iter().enumerate() {
             self.data.insert(col_count * (i + 1) + i, *value)
         }
+        Ok(())
     }
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::{ Tensor, Matrix, Vector, TensorType };
+    use crate::{ Tensor, Matrix, Vector, TensorType, TensorError };
 
     fn matrix123() -> Tensor {
         let data = (1..=9).map(|x| x as f64).collect();
@@ -292,4 +309,26 @@ mod tests {
         ]));
         assert_eq!(expected, recieved);
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn try_append_row_mismatch() {
+        let mut matrix = matrix123();
+        let recieved = matrix.try_append_row(Vector::bra(vec![1.0, 2.0]));
+        let expected = TensorError::ShapeMismatch { operation: "append_row", left: vec![3, 3], right: vec![1, 2] };
+        assert_eq!(recieved, Err(expected));
+        assert_eq!(matrix, matrix123());
+    }
+
+    #[test]
+    fn try_concat_v_mismatch() {
+        let recieved = Matrix::try_concat_v(matrix123(), Vector::bra(vec![1.0, 2.0]));
+        let expected = TensorError::ShapeMismatch { operation: "concat", left: vec![3, 3], right: vec![1, 2] };
+        assert_eq!(recieved, Err(expected));
+    }
+
+    #[test]
+    fn get_cols_out_of_range() {
+        let recieved = matrix123().get_cols(vec![0, 3]);
+        assert_eq!(recieved, Err(TensorError::IndexOutOfRange { index: 3, size: 3 }));
+    }
+}
@@ -2,5 +2,22 @@ pub mod matrix;
 pub mod matrix_row_iterator;
 pub mod matrix_col_iterator;
 pub mod algebra;
+pub mod lu;
+pub mod qr;
+pub mod cholesky;
+pub mod least_squares;
+pub mod eigen;
+pub mod svd;
+pub mod norm;
+pub mod pseudo_inverse;
+
+mod triangular;
 
 pub use matrix::Matrix;
+pub use lu::LuDecomposition;
+pub use qr::QrDecomposition;
+pub use cholesky::CholeskyDecomposition;
+pub use least_squares::lstsq;
+pub use eigen::SymmetricEigen;
+pub use svd::Svd;
+pub use norm::MatrixNorm;
@@ -0,0 +1,71 @@
+use num::Float;
+use crate::Tensor;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum MatrixNorm {
+    Frobenius,
+    /// Max absolute column sum
+    One,
+    /// Max absolute row sum
+    Inf,
+    /// Largest singular value
+    Two
+}
+
+impl<T> Tensor<T> where T: Float {
+    pub fn norm(&self, kind: MatrixNorm) -> T {
+        assert_matrix!(self);
+        match kind {
+            MatrixNorm::Frobenius => self.data.iter().fold(T::zero(), |sum, &value| sum + value * value).sqrt(),
+            MatrixNorm::One => (0..self.col_count())
+                .map(|col| (0..self.row_count()).fold(T::zero(), |sum, row| sum + self.data[row * self.col_count() + col].abs()))
+                .fold(T::zero(), T::max),
+            MatrixNorm::Inf => (0..self.row_count())
+                .map(|row| (0..self.col_count()).fold(T::zero(), |sum, col| sum + self.data[row * self.col_count() + col].abs()))
+                .fold(T::zero(), T::max),
+            MatrixNorm::Two => self.svd().s.data.first().copied().unwrap_or(T::zero())
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Matrix, Tensor};
+    use super::MatrixNorm;
+
+    fn matrix() -> Tensor {
+        Matrix::new(vec![
+            vec![1.0, -2.0],
+            vec![-3.0, 4.0]
+        ])
+    }
+
+    #[test]
+    fn frobenius() {
+        assert!(f64::abs(matrix().norm(MatrixNorm::Frobenius) - f64::sqrt(30.0)) < 1e-12);
+    }
+
+    #[test]
+    fn one() {
+        assert_eq!(matrix().norm(MatrixNorm::One), 6.0);
+    }
+
+    #[test]
+    fn inf() {
+        assert_eq!(matrix().norm(MatrixNorm::Inf), 7.0);
+    }
+
+    #[test]
+    fn two() {
+        assert!(f64::abs(matrix().norm(MatrixNorm::Two) - 5.4649857) < 1e-6);
+    }
+
+    #[test]
+    fn empty() {
+        let matrix: Tensor = Tensor::new(vec![0, 3], 0.0);
+        assert_eq!(matrix.norm(MatrixNorm::Frobenius), 0.0);
+        assert_eq!(matrix.norm(MatrixNorm::One), 0.0);
+        assert_eq!(matrix.norm(MatrixNorm::Inf), 0.0);
+        assert_eq!(matrix.norm(MatrixNorm::Two), 0.0);
+    }
+}
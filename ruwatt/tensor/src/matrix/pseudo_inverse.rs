@@ -0,0 +1,103 @@
+use num::Float;
+use crate::Tensor;
+
+impl<T> Tensor<T> where T: Float {
+    /// Moore–Penrose pseudo-inverse. Singular values below `tol` are treated as zero,
+    /// the default is max(m, n)·eps·s_max.
+    pub fn pinv(&self, tol: Option<T>) -> Tensor<T> {
+        let svd = self.svd();
+        let tol = tol.unwrap_or_else(|| self.default_tolerance(&svd.s));
+        let size = svd.s.data.len();
+        let rows = self.row_count();
+        let cols = self.col_count();
+        let mut data = vec![T::zero(); cols * rows];
+        for (k, &sigma) in svd.s.data.iter().enumerate() {
+            if sigma <= tol {
+                continue;
+            }
+            for i in 0..cols {
+                let v = svd.vt.data[k * cols + i] / sigma;
+                for j in 0..rows {
+                    data[i * rows + j] = data[i * rows + j] + v * svd.u.data[j * size + k];
+                }
+            }
+        }
+        Tensor { shape: vec![cols, rows], data }
+    }
+
+    pub fn rank(&self, tol: Option<T>) -> usize {
+        let s = self.svd().s;
+        let tol = tol.unwrap_or_else(|| self.default_tolerance(&s));
+        s.data.iter().filter(|&&sigma| sigma > tol).count()
+    }
+
+    /// 2-norm condition number s_max / s_min, infinite for singular matrices and zero for empty ones
+    pub fn cond(&self) -> T {
+        let s = self.svd().s;
+        match (s.data.first(), s.data.last()) {
+            (Some(_), Some(&min)) if min == T::zero() => T::infinity(),
+            (Some(&max), Some(&min)) => max / min,
+            _ => T::zero()
+        }
+    }
+
+    fn default_tolerance(&self, s: &Tensor<T>) -> T {
+        let size = self.row_count().max(self.col_count());
+        s.data.first().map_or(T::zero(), |&max| T::from(size).unwrap() * T::epsilon() * max)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{dot, Matrix, Tensor};
+
+    #[test]
+    fn pinv_invertible() {
+        let matrix = Matrix::square(vec![1.0, 2.0, 3.0, 4.0]);
+        assert_near!(matrix.pinv(None), matrix.inverse().unwrap(), 1e-12);
+    }
+
+    #[test]
+    fn pinv_singular() {
+        let matrix = Matrix::square(vec![1.0, 2.0, 2.0, 4.0]);
+        let recieved = matrix.pinv(None);
+        let expected = Matrix::square(vec![0.04, 0.08, 0.08, 0.16]);
+        assert_near!(recieved, expected, 1e-12);
+    }
+
+    #[test]
+    fn pinv_penrose_conditions() {
+        let matrix = Matrix::new(vec![
+            vec![1.0, 2.0, 3.0],
+            vec![4.0, 5.0, 6.0]
+        ]);
+        let pinv = matrix.pinv(None);
+        assert_eq!(pinv.shape, vec![3, 2]);
+        assert_near!(dot(&dot(&matrix, &pinv), &matrix), matrix, 1e-12);
+        assert_near!(dot(&dot(&pinv, &matrix), &pinv), pinv, 1e-12);
+    }
+
+    #[test]
+    fn rank() {
+        let matrix = Matrix::square((1..=9).map(|x| x as f64).collect());
+        assert_eq!(matrix.rank(None), 2);
+        assert_eq!(Matrix::<f64>::ident(3).rank(None), 3);
+        assert_eq!(matrix.rank(Some(100.0)), 0);
+    }
+
+    #[test]
+    fn cond() {
+        let matrix = Matrix::square(vec![2.0, 0.0, 0.0, 0.5]);
+        assert!(f64::abs(matrix.cond() - 4.0) < 1e-12);
+        let singular = Matrix::square(vec![1.0, 0.0, 0.0, 0.0]);
+        assert_eq!(singular.cond(), f64::INFINITY);
+    }
+
+    #[test]
+    fn empty_matrix() {
+        let matrix: Tensor = Tensor { shape: vec![0, 3], data: vec![] };
+        assert_eq!(matrix.pinv(None).shape, vec![3, 0]);
+        assert_eq!(matrix.rank(None), 0);
+        assert_eq!(matrix.cond(), 0.0);
+    }
+}
@@ -0,0 +1,128 @@
+use num::Float;
+use crate::Tensor;
+
+/// Thin Householder QR: A (m×n, m >= n) = Q (m×n) · R (n×n).
+/// The diagonal of R is kept non-negative.
+#[derive(Debug, Clone)]
+pub struct QrDecomposition<T> where T: Float {
+    pub q: Tensor<T>,
+    pub r: Tensor<T>
+}
+
+impl<T> QrDecomposition<T> where T: Float {
+    pub fn new(matrix: &Tensor<T>) -> Self {
+        assert_matrix!(matrix);
+        let rows = matrix.row_count();
+        let cols = matrix.col_count();
+        assert!(rows >= cols, "QR needs at least as many rows as cols: shape = {:?}", matrix.shape);
+
+        let mut data = matrix.data.clone();
+        let mut reflectors: Vec<Vec<T>> = Vec::with_capacity(cols);
+        for k in 0..cols {
+            let v = Self::householder_vector((k..rows).map(|row| data[row * cols + k]).collect());
+            Self::reflect(&v, &mut data, k, cols);
+            reflectors.push(v);
+        }
+
+        let mut r = Tensor::zeros(vec![cols, cols]);
+        for row in 0..cols {
+            for col in row..cols {
+                r.data[row * cols + col] = data[row * cols + col];
+            }
+        }
+
+        let mut q = vec![T::zero(); rows * cols];
+        for i in 0..cols {
+            q[i * cols + i] = T::one();
+        }
+        for (k, v) in reflectors.iter().enumerate().rev() {
+            Self::reflect(v, &mut q, k, cols);
+        }
+
+        for k in 0..cols {
+            if r.data[k * cols + k] < T::zero() {
+                (k..cols).for_each(|col| r.data[k * cols + col] = -r.data[k * cols + col]);
+                (0..rows).for_each(|row| q[row * cols + k] = -q[row * cols + k]);
+            }
+        }
+
+        Self {
+            q: Tensor { shape: vec![rows, cols], data: q },
+            r
+        }
+    }
+
+    fn householder_vector(mut x: Vec<T>) -> Vec<T> {
+        let norm = x.iter().fold(T::zero(), |sum, &value| sum + value * value).sqrt();
+        if norm == T::zero() {
+            return x;
+        }
+        let alpha = if x[0] > T::zero() { -norm } else { norm };
+        x[0] = x[0] - alpha;
+        let length = x.iter().fold(T::zero(), |sum, &value| sum + value * value).sqrt();
+        x.iter_mut().for_each(|value| *value = *value / length);
+        x
+    }
+
+    /// data[k.., ..] -= 2·v·(vᵀ·data[k.., ..])
+    fn reflect(v: &[T], data: &mut [T], k: usize, cols: usize) {
+        let two = T::from(2).unwrap();
+        for col in 0..cols {
+            let projection = v.iter().enumerate()
+                .fold(T::zero(), |sum, (i, &value)| sum + value * data[(k + i) * cols + col]);
+            if projection == T::zero() {
+                continue;
+            }
+            for (i, &value) in v.iter().enumerate() {
+                let index = (k + i) * cols + col;
+                data[index] = data[index] - two * value * projection;
+            }
+        }
+    }
+}
+
+impl<T> Tensor<T> where T: Float {
+    pub fn qr(&self) -> QrDecomposition<T> {
+        QrDecomposition::new(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{dot, Matrix, Tensor};
+
+    fn matrix4x3() -> Tensor {
+        Matrix::new(vec![
+            vec![ 1.0, -1.0,  4.0],
+            vec![ 1.0,  4.0, -2.0],
+            vec![ 1.0,  4.0,  2.0],
+            vec![ 1.0, -1.0,  0.0]
+        ])
+    }
+
+    #[test]
+    fn factorization() {
+        let matrix = matrix4x3();
+        let qr = matrix.qr();
+        assert_eq!(qr.q.shape, vec![4, 3]);
+        assert_eq!(qr.r.shape, vec![3, 3]);
+        assert_near!(dot(&qr.q, &qr.r), matrix, 1e-12);
+    }
+
+    #[test]
+    fn orthonormal_q() {
+        let qr = matrix4x3().qr();
+        assert_near!(dot(&qr.q.tr(), &qr.q), Matrix::<f64>::ident(3), 1e-12);
+    }
+
+    #[test]
+    fn known_r() {
+        let qr = matrix4x3().qr();
+        let expected = Matrix::new(vec![
+            vec![2.0, 3.0,  2.0],
+            vec![0.0, 5.0, -2.0],
+            vec![0.0, 0.0,  4.0]
+        ]);
+        assert_near!(qr.r, expected, 1e-12);
+    }
+}
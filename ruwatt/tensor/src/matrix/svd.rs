@@ -0,0 +1,177 @@
+use num::Float;
+use crate::{Tensor, Vector};
+use super::eigen::{canonical_sign, descending};
+
+const MAX_SWEEPS: usize = 100;
+
+/// Thin SVD: A (m×n) = U (m×k) · diag(s) · Vt (k×n), k = min(m, n).
+/// Singular values are sorted descending, the largest component of every row of Vt is positive.
+/// Singular vectors that belong to zero singular values are left zero:
+/// columns of U when m >= n, rows of Vt when m < n.
+#[derive(Debug, Clone)]
+pub struct Svd<T> where T: Float {
+    pub u: Tensor<T>,
+    pub s: Tensor<T>,
+    pub vt: Tensor<T>
+}
+
+impl<T> Svd<T> where T: Float {
+    pub fn new(matrix: &Tensor<T>) -> Self {
+        assert_matrix!(matrix);
+        let svd = if matrix.row_count() < matrix.col_count() {
+            let svd = Self::one_sided_jacobi(&matrix.tr());
+            Self { u: svd.vt.tr(), s: svd.s, vt: svd.u.tr() }
+        } else {
+            Self::one_sided_jacobi(matrix)
+        };
+        svd.with_canonical_signs()
+    }
+
+    fn with_canonical_signs(mut self) -> Self {
+        let rows = self.u.row_count();
+        let size = self.vt.row_count();
+        let cols = self.vt.col_count();
+        for index in 0..size {
+            let sign = canonical_sign((0..cols).map(|col| self.vt.data[index * cols + col]));
+            if sign < T::zero() {
+                (0..cols).for_each(|col| self.vt.data[index * cols + col] = -self.vt.data[index * cols + col]);
+                (0..rows).for_each(|row| self.u.data[row * size + index] = -self.u.data[row * size + index]);
+            }
+        }
+        self
+    }
+
+    /// Hestenes one-sided Jacobi for m >= n: rotates columns of A until they are orthogonal
+    fn one_sided_jacobi(matrix: &Tensor<T>) -> Self {
+        let rows = matrix.row_count();
+        let cols = matrix.col_count();
+        let mut u = matrix.data.clone();
+        let mut v = vec![T::zero(); cols * cols];
+        for i in 0..cols {
+            v[i * cols + i] = T::one();
+        }
+
+        for _ in 0..MAX_SWEEPS {
+            let mut rotated = false;
+            for p in 0..cols {
+                for q in p + 1..cols {
+                    let (alpha, beta, gamma) = (0..rows).fold((T::zero(), T::zero(), T::zero()), |(a, b, g), k| {
+                        let up = u[k * cols + p];
+                        let uq = u[k * cols + q];
+                        (a + up * up, b + uq * uq, g + up * uq)
+                    });
+                    if gamma.abs() <= T::epsilon() * (alpha * beta).sqrt() || gamma == T::zero() {
+                        continue;
+                    }
+                    rotated = true;
+                    let two = T::from(2).unwrap();
+                    let zeta = (beta - alpha) / (two * gamma);
+                    let t = zeta.signum() / (zeta.abs() + (T::one() + zeta * zeta).sqrt());
+                    let c = T::one() / (T::one() + t * t).sqrt();
+                    let s = c * t;
+                    Self::rotate(&mut u, rows, cols, p, q, c, s);
+                    Self::rotate(&mut v, cols, cols, p, q, c, s);
+                }
+            }
+            if !rotated {
+                break;
+            }
+        }
+
+        let norms: Vec<T> = (0..cols)
+            .map(|col| (0..rows).fold(T::zero(), |sum, row| sum + u[row * cols + col].powi(2)).sqrt())
+            .collect();
+        let mut order: Vec<usize> = (0..cols).collect();
+        order.sort_by(|&i, &j| descending(norms[i], norms[j]));
+
+        let mut u_sorted = vec![T::zero(); rows * cols];
+        let mut vt = vec![T::zero(); cols * cols];
+        for (index, &source) in order.iter().enumerate() {
+            for row in 0..cols {
+                vt[index * cols + row] = v[row * cols + source];
+            }
+            if norms[source] > T::zero() {
+                for row in 0..rows {
+                    u_sorted[row * cols + index] = u[row * cols + source] / norms[source];
+                }
+            }
+        }
+        Self {
+            u: Tensor { shape: vec![rows, cols], data: u_sorted },
+            s: Vector::ket(order.iter().map(|&i| norms[i]).collect()),
+            vt: Tensor { shape: vec![cols, cols], data: vt }
+        }
+    }
+
+    fn rotate(data: &mut [T], rows: usize, cols: usize, p: usize, q: usize, c: T, s: T) {
+        for k in 0..rows {
+            let dp = data[k * cols + p];
+            let dq = data[k * cols + q];
+            data[k * cols + p] = c * dp - s * dq;
+            data[k * cols + q] = s * dp + c * dq;
+        }
+    }
+}
+
+impl<T> Tensor<T> where T: Float {
+    pub fn svd(&self) -> Svd<T> {
+        Svd::new(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{dot, Matrix, Tensor, Vector};
+    use super::Svd;
+
+    fn reconstruct(svd: &Svd<f64>) -> Tensor {
+        let size = svd.s.dim();
+        let mut sigma = Tensor::zeros(vec![size, size]);
+        for i in 0..size {
+            sigma.set(vec![i, i], svd.s.get_v(i));
+        }
+        dot(&dot(&svd.u, &sigma), &svd.vt)
+    }
+
+    #[test]
+    fn known_wide() {
+        let matrix = Matrix::new(vec![
+            vec![3.0, 2.0,  2.0],
+            vec![2.0, 3.0, -2.0]
+        ]);
+        let svd = matrix.svd();
+        assert_eq!(svd.u.shape, vec![2, 2]);
+        assert_eq!(svd.vt.shape, vec![2, 3]);
+        assert_near!(svd.s, Vector::ket(vec![5.0, 3.0]), 1e-12);
+        let half = f64::sqrt(0.5);
+        assert_near!(svd.u, Matrix::square(vec![half, half, half, -half]), 1e-12);
+        assert_near!(reconstruct(&svd), matrix, 1e-12);
+    }
+
+    #[test]
+    fn tall() {
+        let matrix = Matrix::new(vec![
+            vec![1.0, 2.0],
+            vec![3.0, 4.0],
+            vec![5.0, 6.0],
+            vec![7.0, 8.0]
+        ]);
+        let svd = matrix.svd();
+        assert_eq!(svd.u.shape, vec![4, 2]);
+        assert_near!(svd.s, Vector::ket(vec![14.2690954, 0.62682823]), 1e-6);
+        assert_near!(reconstruct(&svd), matrix, 1e-12);
+        assert_near!(dot(&svd.u.tr(), &svd.u), Matrix::<f64>::ident(2), 1e-12);
+        assert_near!(dot(&svd.vt, &svd.vt.tr()), Matrix::<f64>::ident(2), 1e-12);
+    }
+
+    #[test]
+    fn rank_deficient() {
+        let matrix = Matrix::new(vec![
+            vec![1.0, 2.0],
+            vec![2.0, 4.0]
+        ]);
+        let svd = matrix.svd();
+        assert_near!(svd.s, Vector::ket(vec![5.0, 0.0]), 1e-12);
+        assert_near!(reconstruct(&svd), matrix, 1e-12);
+    }
+}
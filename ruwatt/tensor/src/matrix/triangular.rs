@@ -0,0 +1,66 @@
+use num::Float;
+use crate::Tensor;
+
+/// Forward substitution, reads the lower triangle of `matrix` only
+pub(crate) fn solve_lower<T>(matrix: &Tensor<T>, b: &Tensor<T>, unit_diagonal: bool) -> Tensor<T> where T: Float {
+    let size = matrix.row_count();
+    let rhs_count = b.col_count();
+    let mut data = b.data.clone();
+    for rhs in 0..rhs_count {
+        for row in 0..size {
+            let mut value = data[row * rhs_count + rhs];
+            for col in 0..row {
+                value = value - matrix.data[row * size + col] * data[col * rhs_count + rhs];
+            }
+            if !unit_diagonal {
+                value = value / matrix.data[row * size + row];
+            }
+            data[row * rhs_count + rhs] = value;
+        }
+    }
+    Tensor { shape: b.shape.clone(), data }
+}
+
+/// Back substitution, reads the upper triangle of `matrix` only
+pub(crate) fn solve_upper<T>(matrix: &Tensor<T>, b: &Tensor<T>) -> Tensor<T> where T: Float {
+    let size = matrix.col_count();
+    let rhs_count = b.col_count();
+    let mut data = b.data.clone();
+    for rhs in 0..rhs_count {
+        for row in (0..size).rev() {
+            let mut value = data[row * rhs_count + rhs];
+            for col in row + 1..size {
+                value = value - matrix.data[row * size + col] * data[col * rhs_count + rhs];
+            }
+            data[row * rhs_count + rhs] = value / matrix.data[row * size + row];
+        }
+    }
+    Tensor { shape: b.shape.clone(), data }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Matrix, Vector};
+    use super::{solve_lower, solve_upper};
+
+    #[test]
+    fn lower() {
+        let matrix = Matrix::new(vec![
+            vec![2.0, 9.0],
+            vec![1.0, 4.0]
+        ]);
+        let b = Vector::ket(vec![2.0, 9.0]);
+        assert_eq!(solve_lower(&matrix, &b, false), Vector::ket(vec![1.0, 2.0]));
+        assert_eq!(solve_lower(&matrix, &b, true), Vector::ket(vec![2.0, 7.0]));
+    }
+
+    #[test]
+    fn upper() {
+        let matrix = Matrix::new(vec![
+            vec![2.0, 1.0],
+            vec![9.0, 4.0]
+        ]);
+        let b = Vector::ket(vec![4.0, 8.0]);
+        assert_eq!(solve_upper(&matrix, &b), Vector::ket(vec![1.0, 2.0]));
+    }
+}
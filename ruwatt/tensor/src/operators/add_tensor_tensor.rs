@@ -1,12 +1,18 @@
 use std::ops;
 use num::Float;
-use crate::Tensor;
-use super::arithmetic::arithmetic;
+use crate::{Tensor, TensorError};
+use super::arithmetic::{arithmetic, try_arithmetic};
 
 fn add_tensor_tensor<T: Float>(a: &Tensor<T>, b: &Tensor<T>) -> Tensor<T> {
     return arithmetic(a, b, &|&x, &y| x + y);
 }
 
+impl<T> Tensor<T> where T: Float {
+    pub fn try_add(&self, other: &Tensor<T>) -> Result<Tensor<T>, TensorError> {
+        try_arithmetic(self, other, &|&x, &y| x + y)
+    }
+}
+
 impl<T> ops::Add<&Tensor<T>> for &Tensor<T> where T: Float {
     type Output = Tensor<T>;
 
@@ -41,7 +47,7 @@ impl<T> ops::Add<Tensor<T>> for Tensor<T> where T: Float {
 
 #[cfg(test)]
 mod tests {
-    use crate::{Vector, Scalar, Matrix, Tensor};
+    use crate::{Vector, Scalar, Matrix, Tensor, TensorError};
 
     fn matrix1234() -> Tensor<f64> {
         Matrix::square(vec![1.0, 2.0, 3.0, 4.0])
@@ -115,4 +121,13 @@ mod tests {
         ]);
         assert_eq!(expected, a + b)
     }
+
+    #[test]
+    fn try_add_incompatible() {
+        let a = Vector::bra(vec![ 1.0, 2.0, 3.0 ]);
+        let b = matrix1234();
+        let expected = TensorError::ShapeMismatch { operation: "broadcast", left: vec![1, 3], right: vec![2, 2] };
+        assert_eq!(a.try_add(&b), Err(expected));
+        assert_eq!(b.try_add(&b), Ok(&b + &b));
+    }
 }
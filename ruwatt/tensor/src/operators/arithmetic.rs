@@ -1,37 +1,75 @@
 use num::Float;
-use super::super::{ Tensor, TensorType, Vector, VectorType };
+use super::super::{ Tensor, TensorError, TensorType, TensorView };
 
+/// Elementwise operation with NumPy-style broadcasting.
+/// Tensors with all dimensions equal to 1 act as scalars and keep the shape of the other operand.
 pub fn arithmetic<T: Float>(a: &Tensor<T>, b: &Tensor<T>, f: &dyn Fn(&T, &T) -> T) -> Tensor<T> {
+    try_arithmetic(a, b, f).unwrap_or_else(|error| panic!("{error}"))
+}
+
+pub fn try_arithmetic<T: Float>(a: &Tensor<T>, b: &Tensor<T>, f: &dyn Fn(&T, &T) -> T) -> Result<Tensor<T>, TensorError> {
     if a.shape == b.shape {
-        return arithmetic_same_shape(a, b, f);
+        return Ok(arithmetic_same_shape(a, b, f));
     }
 
     if let TensorType::Scalar(val) = a.get_type() {
-        return arithmetic_scalar_tensor(val, b, f);
+        return Ok(arithmetic_scalar_tensor(val, b, f));
     }
 
     if let TensorType::Scalar(val) = b.get_type() {
-        return arithmetic_tensor_scalar(a, val, f);
+        return Ok(arithmetic_tensor_scalar(a, val, f));
     }
 
-    if let TensorType::Vector(vector_type) = a.get_type() {
-        if b.is_matrix() {
-            match vector_type {
-                VectorType::Bra => return arithmetic_bra_matrix(a, b, f),
-                VectorType::Ket => return arithmetic_ket_matrix(a, b, f)
-            }
-        }
+    try_arithmetic_views(&a.view(), &b.view(), f)
+}
+
+/// Same as `arithmetic` for strided views
+pub fn arithmetic_views<T: Float>(a: &TensorView<T>, b: &TensorView<T>, f: &dyn Fn(&T, &T) -> T) -> Tensor<T> {
+    try_arithmetic_views(a, b, f).unwrap_or_else(|error| panic!("{error}"))
+}
+
+pub fn try_arithmetic_views<T: Float>(a: &TensorView<T>, b: &TensorView<T>, f: &dyn Fn(&T, &T) -> T) -> Result<Tensor<T>, TensorError> {
+    let is_scalar = |view: &TensorView<T>| view.size() == 1 && view.shape.iter().all(|&dim| dim == 1);
+    let shape = if a.shape != b.shape && is_scalar(a) {
+        b.shape.clone()
+    } else if a.shape != b.shape && is_scalar(b) {
+        a.shape.clone()
+    } else {
+        broadcast_shape(&a.shape, &b.shape).ok_or_else(|| {
+            TensorError::ShapeMismatch { operation: "broadcast", left: a.shape.clone(), right: b.shape.clone() }
+        })?
+    };
+    let a = stretch(a, &shape);
+    let b = stretch(b, &shape);
+    let data = a.iter().zip(b.iter()).map(|(x, y)| f(&x, &y)).collect();
+    Ok(Tensor { data, shape })
+}
+
+/// Result shape of broadcasting `a` against `b`: shapes are aligned from the last axis,
+/// each pair of dimensions has to be equal or contain 1.
+pub fn broadcast_shape(a: &[usize], b: &[usize]) -> Option<Vec<usize>> {
+    let rank = a.len().max(b.len());
+    let mut shape = vec![0; rank];
+    for i in 0..rank {
+        let dim_a = if i < a.len() { a[a.len() - 1 - i] } else { 1 };
+        let dim_b = if i < b.len() { b[b.len() - 1 - i] } else { 1 };
+        shape[rank - 1 - i] = match (dim_a, dim_b) {
+            _ if dim_a == dim_b => dim_a,
+            (1, _) => dim_b,
+            (_, 1) => dim_a,
+            _ => return None
+        };
     }
-  
-    if let TensorType::Vector(vector_type) = b.get_type() {
-        if a.is_matrix() {
-            match vector_type {
-                VectorType::Bra => return arithmetic_matrix_bra(a, b, f),
-                VectorType::Ket => return arithmetic_matrix_ket(a, b, f)
-            }
-        }
+    Some(shape)
+}
+
+/// Broadcasts a view to `shape`, scalars of any rank included
+fn stretch<'a, T: Float>(view: &TensorView<'a, T>, shape: &[usize]) -> TensorView<'a, T> {
+    if view.shape.len() > shape.len() {
+        let scalar = TensorView { data: view.data, shape: vec![], strides: vec![], offset: view.offset };
+        return scalar.broadcast_to(shape).unwrap();
     }
-    panic!("Unsupported shape {:?} vs {:?}", a.shape, b.shape);
+    view.broadcast_to(shape).unwrap()
 }
 
 fn arithmetic_same_shape<T: Float>(a: &Tensor<T>, b: &Tensor<T>, f: &dyn Fn(&T, &T) -> T) -> Tensor<T> {
@@ -52,42 +90,54 @@ fn arithmetic_tensor_scalar<T: Float>(a: &Tensor<T>, val: T, f: &dyn Fn(&T, &T)
     Tensor { data, shape }
 }
 
-fn arithmetic_bra_matrix<T: Float>(a: &Tensor<T>, b: &Tensor<T>, f: &dyn Fn(&T, &T) -> T) -> Tensor<T> {
-    assert_eq!(a.col_count(), b.col_count());
-    let mut result = Tensor::empty();
-    b.rows().for_each(|row| {
-        let data = a.data.iter().zip(row.data.iter()).map(|(val_a, val_b)| f(val_a, val_b)).collect();
-        result.append_row(Vector::bra(data));
-    });
-    result 
-}
+#[cfg(test)]
+mod tests {
+    use crate::{Tensor, Vector};
+    use super::{arithmetic, broadcast_shape};
 
-fn arithmetic_ket_matrix<T: Float>(a: &Tensor<T>, b: &Tensor<T>, f: &dyn Fn(&T, &T) -> T) -> Tensor<T> {
-    assert_eq!(a.row_count(), b.row_count());
-    let mut result = Tensor::empty();
-    b.cols().for_each(|col| {
-        let data = a.data.iter().zip(col.data.iter()).map(|(val_a, val_b)| f(val_a, val_b)).collect();
-        result.append_col(Vector::ket(data));
-    });
-    result
-}
+    #[test]
+    fn broadcast_shapes() {
+        assert_eq!(broadcast_shape(&[2, 3], &[1, 3]), Some(vec![2, 3]));
+        assert_eq!(broadcast_shape(&[4, 1, 3], &[2, 1]), Some(vec![4, 2, 3]));
+        assert_eq!(broadcast_shape(&[3], &[2, 1]), Some(vec![2, 3]));
+        assert_eq!(broadcast_shape(&[2, 3], &[3, 2]), None);
+    }
 
-fn arithmetic_matrix_bra<T: Float>(a: &Tensor<T>, b: &Tensor<T>, f: &dyn Fn(&T, &T) -> T) -> Tensor<T> {
-    assert_eq!(a.col_count(), b.col_count());
-    let mut result = Tensor::empty();
-    a.rows().for_each(|row| {
-        let data = row.data.iter().zip(b.data.iter()).map(|(val_a, val_b)| f(val_a, val_b)).collect();
-        result.append_row(Vector::bra(data));
-    });
-    result
-}
+    #[test]
+    fn bra_ket_outer() {
+        let a = Vector::bra(vec![1.0, 2.0, 3.0]);
+        let b = Vector::ket(vec![10.0, 20.0]);
+        let recieved = arithmetic(&a, &b, &|&x, &y| x + y);
+        let expected = Tensor { shape: vec![2, 3], data: vec![11.0, 12.0, 13.0, 21.0, 22.0, 23.0] };
+        assert_eq!(recieved, expected);
+    }
 
-fn arithmetic_matrix_ket<T: Float>(a: &Tensor<T>, b: &Tensor<T>, f: &dyn Fn(&T, &T) -> T) -> Tensor<T> {
-    assert_eq!(a.row_count(), b.row_count());
-    let mut result = Tensor::empty();
-    a.cols().for_each(|col| {
-        let data = col.data.iter().zip(b.data.iter()).map(|(val_a, val_b)| f(val_a, val_b)).collect();
-        result.append_col(Vector::ket(data));
-    });
-    result
+    #[test]
+    fn rank3() {
+        let a = Tensor::range(0.0, vec![2, 2, 3]);
+        let b = Tensor { shape: vec![2, 1], data: vec![100.0, 200.0] };
+        let recieved = arithmetic(&a, &b, &|&x, &y| x + y);
+        let expected = Tensor {
+            shape: vec![2, 2, 3],
+            data: vec![100.0, 101.0, 102.0, 203.0, 204.0, 205.0, 106.0, 107.0, 108.0, 209.0, 210.0, 211.0]
+        };
+        assert_eq!(recieved, expected);
+    }
+
+    #[test]
+    fn lower_rank() {
+        let a = Tensor::range(1.0, vec![2, 3]);
+        let b = Tensor { shape: vec![3], data: vec![1.0, 2.0, 3.0] };
+        let recieved = arithmetic(&a, &b, &|&x, &y| x * y);
+        let expected = Tensor { shape: vec![2, 3], data: vec![1.0, 4.0, 9.0, 4.0, 10.0, 18.0] };
+        assert_eq!(recieved, expected);
+    }
+
+    #[test]
+    #[should_panic(expected = "Incompatible shapes to broadcast: [2, 3] vs [3, 2]")]
+    fn incompatible() {
+        let a = Tensor::<f64>::zeros(vec![2, 3]);
+        let b = Tensor::<f64>::zeros(vec![3, 2]);
+        arithmetic(&a, &b, &|&x, &y| x + y);
+    }
 }
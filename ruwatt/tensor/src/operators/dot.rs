@@ -1,60 +1,60 @@
 use num::Float;
 use std::iter::Sum;
-use crate::assert_dot_able;
-use crate::{Tensor, IndexTools, TensorType, Scalar};
+use super::gemm::gemm;
+use super::tensordot::tensordot;
+use crate::{Tensor, TensorError, TensorType, TensorView, Scalar};
 
-pub fn dot<T>(a: &Tensor<T>, b: &Tensor<T>) -> Tensor<T> where T: Float + Sum {
+pub fn dot<T>(a: &Tensor<T>, b: &Tensor<T>) -> Tensor<T> where T: Float + Sum + Send + Sync {
+    assert!(!a.is_empty() && !b.is_empty(), "Can't dot empty tensors. {:?} . {:?}", a.shape, b.shape);
+    try_dot(a, b).unwrap_or_else(|error| panic!("{error}"))
+}
+
+pub fn try_dot<T>(a: &Tensor<T>, b: &Tensor<T>) -> Result<Tensor<T>, TensorError> where T: Float + Sum + Send + Sync {
+    let mismatch = || TensorError::ShapeMismatch { operation: "dot", left: a.shape.clone(), right: b.shape.clone() };
     match (a.get_type(), b.get_type()) {
         (TensorType::Empty, _) | (_, TensorType::Empty) => {
-            panic!("Can't dot empty tensors. {:?} . {:?}", a.shape, b.shape)
+            Err(mismatch())
         },
         (TensorType::Scalar(val_a), TensorType::Scalar(val_b)) => {
-            Scalar::new(val_a * val_b)
+            Ok(Scalar::new(val_a * val_b))
         },
         (TensorType::Scalar(val_a), _) => {
-            b.clone() * val_a
+            Ok(b.clone() * val_a)
         },
         (_, TensorType::Scalar(val_b)) => {
-            a.clone() * val_b
+            Ok(a.clone() * val_b)
         },
         (TensorType::Vector(_), TensorType::Vector(_)) |
         (TensorType::Vector(_), TensorType::Matrix) |
         (TensorType::Matrix, TensorType::Vector(_)) |
         (TensorType::Matrix, TensorType::Matrix) => {
-            dot_matrix(a, b)
+            if a.col_count() != b.row_count() {
+                return Err(mismatch());
+            }
+            Ok(dot_view(&a.view(), &b.view()))
         },
         _ => {
-            unimplemented!("This method is not yet implemented. {:?} . {:?}", a.shape, b.shape);
+            // NumPy rule: sums over the last axis of `a` and the second-to-last axis of `b`
+            let axis_a = a.shape.len() - 1;
+            let axis_b = b.shape.len().saturating_sub(2);
+            if a.shape[axis_a] != b.shape[axis_b] {
+                return Err(mismatch());
+            }
+            Ok(tensordot(a, b, (&[axis_a], &[axis_b])))
         }
     }
 }
 
-fn dot_matrix<T>(a: &Tensor<T>, b: &Tensor<T>) -> Tensor<T> where T: Float + Sum {
-    assert_dot_able!(a, b);
-
-    let row_count = a.row_count();
-    let col_count = b.col_count();
-    let shape = vec![row_count, col_count];
-    let mut data = vec![T::zero(); row_count * col_count];
-
-    for row_index in 0..row_count {
-        let row = IndexTools::<T>::row(row_index, &a.shape, &a.data).unwrap();
-        for col_index in 0..col_count {
-            let col = IndexTools::<T>::col(col_index, &b.shape, &b.data).unwrap();
-            let value = row.iter().zip(col.iter()).map(|(&a, &b)| a*b).sum();
-            let index = row_index * col_count + col_index;
-            if index >= data.len() {
-                println!("{:?} * {:?}", a.shape, b.shape);
-            }
-            data[index] = value;
-        }
-    }
-    Tensor { shape, data }
+/// Matrix product of two strided 2-D views, e.g. `dot_view(&a.view().tr(), &b.view())`
+/// multiplies by the transpose without copying it.
+pub fn dot_view<T>(a: &TensorView<T>, b: &TensorView<T>) -> Tensor<T> where T: Float + Send + Sync {
+    gemm(a, b)
 }
 
 #[cfg(test)]
 mod tests {
-    use super::dot;
+    use super::{dot, dot_view, try_dot};
+    use crate::TensorError;
     use super::super::super::{Tensor, Matrix, Vector, Scalar};
 
     #[test]
@@ -81,6 +81,14 @@ mod tests {
         dot(&vector, &vector);
     }
 
+    #[test]
+    fn try_dot_incompatible() {
+        let vector = Vector::bra(vec![ 1.0, 2.0 ]);
+        let expected = TensorError::ShapeMismatch { operation: "dot", left: vec![1, 2], right: vec![1, 2] };
+        assert_eq!(try_dot(&vector, &vector), Err(expected));
+        assert_eq!(try_dot(&vector, &vector.tr()), Ok(Scalar::new(5.0).reshape(&[1, 1])));
+    }
+
     #[test]
     fn dot_vector_vector() {
         let bra = Vector::bra(vec![ 1.0, 2.0 ]);
@@ -121,4 +129,28 @@ mod tests {
         let expected = Vector::ket(vec![ 14.0, 32.0, 50.0]);
         assert_eq!(expected, recieved)
     }
+
+    #[test]
+    fn dot_general() {
+        let a = Tensor::range(0.0, vec![2, 2, 3]);
+        let b = Vector::ket(vec![ 1.0, 0.0, 2.0 ]);
+        let recieved = dot(&a, &b);
+        let expected = Tensor { shape: vec![2, 2, 1], data: vec![ 4.0, 13.0, 22.0, 31.0 ] };
+        assert_eq!(expected, recieved)
+    }
+
+    #[test]
+    fn dot_transposed_view() {
+        let a = Matrix::new(vec![
+            vec![ 1.0, 2.0, 3.0 ],
+            vec![ 4.0, 5.0, 6.0 ]
+        ]);
+        let recieved = dot_view(&a.view().tr(), &a.view().slice(&[0..2, 1..3]));
+        let expected = Matrix::new(vec![
+            vec![ 22.0, 27.0 ],
+            vec![ 29.0, 36.0 ],
+            vec![ 36.0, 45.0 ]
+        ]);
+        assert_eq!(expected, recieved)
+    }
 }
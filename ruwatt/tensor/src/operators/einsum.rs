@@ -0,0 +1,144 @@
+use num::Float;
+use crate::Tensor;
+
+/// Einstein summation over `operands`, e.g. `einsum("ij,jk->ik", &[&a, &b])`.
+/// Letters missing in the output are summed over, a letter repeated within one operand
+/// takes its diagonal (`"ii->"` is the trace). Without `->` the output consists of
+/// the letters used exactly once, in alphabetical order.
+pub fn einsum<T>(subscripts: &str, operands: &[&Tensor<T>]) -> Tensor<T> where T: Float {
+    let subscripts: String = subscripts.chars().filter(|c| !c.is_whitespace()).collect();
+    let (inputs, output) = match subscripts.split_once("->") {
+        Some((inputs, output)) => (inputs, output.chars().collect::<Vec<_>>()),
+        None => (subscripts.as_str(), implicit_output(&subscripts))
+    };
+    let inputs: Vec<Vec<char>> = inputs.split(',').map(|input| input.chars().collect()).collect();
+    assert!(
+        inputs.iter().chain(std::iter::once(&output)).flatten().all(|c| c.is_ascii_alphabetic()),
+        "Invalid einsum subscripts: {}", subscripts
+    );
+    assert_eq!(inputs.len(), operands.len(), "Einsum expects {} operands, got {}", inputs.len(), operands.len());
+
+    let mut letters: Vec<char> = output.clone();
+    let mut dims: Vec<Option<usize>> = vec![None; output.len()];
+    for (input, operand) in inputs.iter().zip(operands.iter()) {
+        assert_eq!(
+            input.len(), operand.shape.len(),
+            "Subscripts {:?} don't match shape {:?}", input.iter().collect::<String>(), operand.shape
+        );
+        for (&letter, &dim) in input.iter().zip(operand.shape.iter()) {
+            if !letters.contains(&letter) {
+                letters.push(letter);
+                dims.push(None);
+            }
+            let position = letters.iter().position(|&c| c == letter).unwrap();
+            match dims[position] {
+                Some(known) => assert_eq!(known, dim, "Dimension mismatch for index '{}': {} vs {}", letter, known, dim),
+                None => dims[position] = Some(dim)
+            }
+        }
+    }
+    assert!(dims.iter().all(Option::is_some), "Output index without input in einsum subscripts: {}", subscripts);
+    let dims: Vec<usize> = dims.into_iter().flatten().collect();
+
+    // stride of every letter in every operand, repeated letters add up to the diagonal stride
+    let strides: Vec<Vec<usize>> = inputs.iter().zip(operands.iter())
+        .map(|(input, operand)| {
+            let operand_strides = operand.view().strides;
+            let mut strides = vec![0; letters.len()];
+            for (&letter, &stride) in input.iter().zip(operand_strides.iter()) {
+                strides[letters.iter().position(|&c| c == letter).unwrap()] += stride;
+            }
+            strides
+        })
+        .collect();
+
+    let output_size: usize = dims[..output.len()].iter().product();
+    let summed_size: usize = dims[output.len()..].iter().product();
+    let mut data = vec![T::zero(); output_size];
+    let mut indices = vec![0; letters.len()];
+    let mut offsets = vec![0; operands.len()];
+    for value in data.iter_mut() {
+        for _ in 0..summed_size {
+            *value = *value + offsets.iter().zip(operands.iter())
+                .fold(T::one(), |product, (&offset, operand)| product * operand.data[offset]);
+            advance(&mut indices, &mut offsets, &dims, &strides);
+        }
+    }
+    Tensor { shape: dims[..output.len()].to_vec(), data }
+}
+
+fn implicit_output(inputs: &str) -> Vec<char> {
+    let mut output: Vec<char> = inputs.chars()
+        .filter(|&c| c != ',' && inputs.matches(c).count() == 1)
+        .collect();
+    output.sort();
+    output
+}
+
+/// Row-major odometer step over all letters, keeping the operand offsets in sync
+fn advance(indices: &mut [usize], offsets: &mut [usize], dims: &[usize], strides: &[Vec<usize>]) {
+    for axis in (0..dims.len()).rev() {
+        indices[axis] += 1;
+        offsets.iter_mut().zip(strides.iter()).for_each(|(offset, strides)| *offset += strides[axis]);
+        if indices[axis] < dims[axis] {
+            return;
+        }
+        offsets.iter_mut().zip(strides.iter()).for_each(|(offset, strides)| *offset -= strides[axis] * dims[axis]);
+        indices[axis] = 0;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{dot, Matrix, Scalar, Tensor};
+    use super::einsum;
+
+    fn matrix() -> Tensor {
+        Matrix::new(vec![vec![1.0, 2.0, 3.0], vec![4.0, 5.0, 6.0], vec![7.0, 8.0, 9.0]])
+    }
+
+    #[test]
+    fn matrix_product() {
+        let a = matrix();
+        let b = Matrix::new(vec![vec![1.0, 0.0], vec![0.0, 1.0], vec![1.0, 1.0]]);
+        assert_eq!(einsum("ij,jk->ik", &[&a, &b]), dot(&a, &b));
+        assert_eq!(einsum("ij,jk", &[&a, &b]), dot(&a, &b));
+        assert_eq!(einsum("ji", &[&a]), a.tr());
+    }
+
+    #[test]
+    fn trace_and_diagonal() {
+        let a = matrix();
+        assert_eq!(einsum("ii->", &[&a]), Scalar::new(15.0));
+        assert_eq!(einsum("ii->i", &[&a]), Tensor { shape: vec![3], data: vec![1.0, 5.0, 9.0] });
+    }
+
+    #[test]
+    fn outer() {
+        let a = Tensor { shape: vec![2], data: vec![1.0, 2.0] };
+        let b = Tensor { shape: vec![2], data: vec![3.0, 4.0] };
+        assert_eq!(einsum("i,j->ij", &[&a, &b]), Matrix::square(vec![3.0, 4.0, 6.0, 8.0]));
+        assert_eq!(einsum("i,i->", &[&a, &b]), Scalar::new(11.0));
+    }
+
+    #[test]
+    fn batched_matmul() {
+        let a = Tensor::range(0.0, vec![2, 2, 3]);
+        let b = Tensor::range(1.0, vec![2, 3, 2]);
+        let recieved = einsum("bij,bjk->bik", &[&a, &b]);
+        assert_eq!(recieved.shape, vec![2, 2, 2]);
+        for batch in 0..2 {
+            let a_batch = Tensor { shape: vec![2, 3], data: a.data[batch * 6..(batch + 1) * 6].to_vec() };
+            let b_batch = Tensor { shape: vec![3, 2], data: b.data[batch * 6..(batch + 1) * 6].to_vec() };
+            assert_eq!(recieved.data[batch * 4..(batch + 1) * 4], dot(&a_batch, &b_batch).data[..]);
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "Dimension mismatch for index 'j': 3 vs 2")]
+    fn dimension_mismatch() {
+        let a = matrix();
+        let b = Tensor::<f64>::zeros(vec![2, 2]);
+        einsum("ij,jk->ik", &[&a, &b]);
+    }
+}
@@ -0,0 +1,111 @@
+use num::Float;
+use crate::{Tensor, TensorView};
+
+#[cfg(feature = "rayon")]
+use rayon::prelude::*;
+
+const BLOCK_ROWS: usize = 64;
+const BLOCK_COLS: usize = 64;
+const BLOCK_INNER: usize = 256;
+
+/// Cache-blocked C = A·B for 2-D views.
+/// B is transposed once so that both operands are read along contiguous rows.
+/// Every element of C is accumulated in the natural order of the inner index,
+/// so the result does not depend on the block sizes or on the number of threads.
+pub(crate) fn gemm<T>(a: &TensorView<T>, b: &TensorView<T>) -> Tensor<T> where T: Float + Send + Sync {
+    assert_dot_able!(a, b);
+
+    let row_count = a.row_count();
+    let col_count = b.col_count();
+    let inner_count = a.col_count();
+    let mut data = vec![T::zero(); row_count * col_count];
+    if row_count * col_count == 0 {
+        return Tensor { shape: vec![row_count, col_count], data };
+    }
+
+    let a_packed;
+    let a_data = if a.is_contiguous() {
+        &a.data[a.offset..a.offset + a.size()]
+    } else {
+        a_packed = a.iter().collect::<Vec<_>>();
+        &a_packed[..]
+    };
+    let bt = b.tr().iter().collect::<Vec<_>>();
+
+    let block = |(block_index, out): (usize, &mut [T])| {
+        multiply_block(a_data, &bt, out, block_index * BLOCK_ROWS, inner_count, col_count)
+    };
+
+    #[cfg(feature = "rayon")]
+    data.par_chunks_mut(BLOCK_ROWS * col_count).enumerate().for_each(block);
+
+    #[cfg(not(feature = "rayon"))]
+    data.chunks_mut(BLOCK_ROWS * col_count).enumerate().for_each(block);
+
+    Tensor { shape: vec![row_count, col_count], data }
+}
+
+/// Fills rows `first_row..` of C that are stored in `out`
+fn multiply_block<T>(a: &[T], bt: &[T], out: &mut [T], first_row: usize, inner_count: usize, col_count: usize) where T: Float {
+    let rows = out.len() / col_count;
+    for inner_start in (0..inner_count).step_by(BLOCK_INNER) {
+        let inner_end = (inner_start + BLOCK_INNER).min(inner_count);
+        for col_start in (0..col_count).step_by(BLOCK_COLS) {
+            let col_end = (col_start + BLOCK_COLS).min(col_count);
+            for row in 0..rows {
+                let a_row = &a[(first_row + row) * inner_count + inner_start..(first_row + row) * inner_count + inner_end];
+                for col in col_start..col_end {
+                    let b_col = &bt[col * inner_count + inner_start..col * inner_count + inner_end];
+                    let index = row * col_count + col;
+                    out[index] = a_row.iter().zip(b_col).fold(out[index], |sum, (&x, &y)| sum + x * y);
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Tensor;
+    use super::gemm;
+
+    fn naive(a: &Tensor, b: &Tensor) -> Tensor {
+        let (rows, inner, cols) = (a.row_count(), a.col_count(), b.col_count());
+        let mut data = vec![0.0; rows * cols];
+        for row in 0..rows {
+            for col in 0..cols {
+                data[row * cols + col] = (0..inner)
+                    .fold(0.0, |sum, k| sum + a.data[row * inner + k] * b.data[k * cols + col]);
+            }
+        }
+        Tensor { shape: vec![rows, cols], data }
+    }
+
+    fn pseudo_random(rows: usize, cols: usize, seed: usize) -> Tensor {
+        let data = (0..rows * cols).map(|i| (((i + seed) * 7919 % 1000) as f64) / 250.0 - 2.0).collect();
+        Tensor { shape: vec![rows, cols], data }
+    }
+
+    #[test]
+    fn matches_naive_bit_for_bit() {
+        let a = pseudo_random(130, 300, 1);
+        let b = pseudo_random(300, 70, 2);
+        assert_eq!(gemm(&a.view(), &b.view()), naive(&a, &b));
+    }
+
+    #[test]
+    fn strided_operands() {
+        let a = pseudo_random(70, 90, 3);
+        let b = pseudo_random(70, 90, 4);
+        let recieved = gemm(&a.view().tr(), &b.view().slice(&[0..70, 10..80]));
+        let expected = naive(&a.tr(), &b.view().slice(&[0..70, 10..80]).to_tensor());
+        assert_eq!(recieved, expected);
+    }
+
+    #[test]
+    fn empty_result() {
+        let a = Tensor::<f64>::zeros(vec![0, 3]);
+        let b = Tensor::<f64>::zeros(vec![3, 2]);
+        assert_eq!(gemm(&a.view(), &b.view()).shape, vec![0, 2]);
+    }
+}
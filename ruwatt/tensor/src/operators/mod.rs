@@ -1,4 +1,5 @@
 mod arithmetic; 
+mod gemm;
 
 pub mod div_tensor_number;
 pub mod div_tensor_tensor;
@@ -9,5 +10,10 @@ pub mod sub_tensor_tensor;
 pub mod mul_tensor_number;
 pub mod mul_tensor_tensor;
 pub mod dot;
+pub mod tensordot;
+pub mod einsum;
 
-pub use dot::dot;
+pub use dot::{dot, dot_view, try_dot};
+pub use tensordot::tensordot;
+pub use einsum::einsum;
+pub use arithmetic::{arithmetic_views, broadcast_shape};
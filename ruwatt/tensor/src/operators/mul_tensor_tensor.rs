@@ -1,12 +1,18 @@
 use std::ops;
 use num::Float;
-use super::super::Tensor;
-use super::arithmetic::arithmetic;
+use super::super::{Tensor, TensorError};
+use super::arithmetic::{arithmetic, try_arithmetic};
 
 fn mul_tensor_tensor<T: Float>(a: &Tensor<T>, b: &Tensor<T>) -> Tensor<T> {
     return arithmetic(a, b, &|&x, &y| x * y);
 }
 
+impl<T> Tensor<T> where T: Float {
+    pub fn try_mul(&self, other: &Tensor<T>) -> Result<Tensor<T>, TensorError> {
+        try_arithmetic(self, other, &|&x, &y| x * y)
+    }
+}
+
 impl<T> ops::Mul<&Tensor<T>> for &Tensor<T> where T: Float {
   type Output = Tensor<T>;
 
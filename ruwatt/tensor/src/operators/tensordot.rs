@@ -0,0 +1,78 @@
+use num::Float;
+use crate::Tensor;
+use super::gemm::gemm;
+
+/// Sums products over `axes.0` of `a` paired with `axes.1` of `b`.
+/// The result has the free axes of `a` followed by the free axes of `b`.
+pub fn tensordot<T>(a: &Tensor<T>, b: &Tensor<T>, axes: (&[usize], &[usize])) -> Tensor<T> where T: Float + Send + Sync {
+    let (axes_a, axes_b) = axes;
+    assert_eq!(axes_a.len(), axes_b.len(), "Axes count mismatch to tensordot: {:?} vs {:?}", axes_a, axes_b);
+    for (&axis_a, &axis_b) in axes_a.iter().zip(axes_b.iter()) {
+        assert!(
+            axis_a < a.shape.len() && axis_b < b.shape.len() && a.shape[axis_a] == b.shape[axis_b],
+            "Incompatible shapes to tensordot: {:?} vs {:?} along axes {:?} and {:?}",
+            a.shape, b.shape, axes_a, axes_b
+        );
+    }
+
+    let free_a: Vec<usize> = (0..a.shape.len()).filter(|axis| !axes_a.contains(axis)).collect();
+    let free_b: Vec<usize> = (0..b.shape.len()).filter(|axis| !axes_b.contains(axis)).collect();
+    let rows: usize = free_a.iter().map(|&axis| a.shape[axis]).product();
+    let cols: usize = free_b.iter().map(|&axis| b.shape[axis]).product();
+    let inner: usize = axes_a.iter().map(|&axis| a.shape[axis]).product();
+
+    let order_a: Vec<usize> = free_a.iter().chain(axes_a.iter()).cloned().collect();
+    let order_b: Vec<usize> = axes_b.iter().chain(free_b.iter()).cloned().collect();
+    let a_matrix = Tensor { shape: vec![rows, inner], data: a.view().permute(&order_a).to_tensor().data };
+    let b_matrix = Tensor { shape: vec![inner, cols], data: b.view().permute(&order_b).to_tensor().data };
+    let product = gemm(&a_matrix.view(), &b_matrix.view());
+
+    let shape = free_a.iter().map(|&axis| a.shape[axis])
+        .chain(free_b.iter().map(|&axis| b.shape[axis]))
+        .collect();
+    Tensor { shape, data: product.data }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{dot, Matrix, Tensor};
+    use super::tensordot;
+
+    #[test]
+    fn matrix_product() {
+        let a = Matrix::new(vec![vec![1.0, 2.0, 3.0], vec![4.0, 5.0, 6.0]]);
+        let b = Matrix::new(vec![vec![1.0, 2.0], vec![3.0, 4.0], vec![5.0, 6.0]]);
+        assert_eq!(tensordot(&a, &b, (&[1], &[0])), dot(&a, &b));
+        assert_eq!(tensordot(&a, &a, (&[1], &[1])), dot(&a, &a.tr()));
+    }
+
+    #[test]
+    fn rank3() {
+        let a = Tensor::range(0.0, vec![3, 4, 5]);
+        let b = Tensor::range(0.0, vec![4, 3, 2]);
+        let recieved = tensordot(&a, &b, (&[1, 0], &[0, 1]));
+        assert_eq!(recieved.shape, vec![5, 2]);
+        let mut expected = 0.0;
+        for i in 0..3 {
+            for j in 0..4 {
+                expected += a.get(vec![i, j, 4]) * b.get(vec![j, i, 1]);
+            }
+        }
+        assert_eq!(recieved.get(vec![4, 1]), expected);
+    }
+
+    #[test]
+    fn outer() {
+        let a = Tensor { shape: vec![2], data: vec![1.0, 2.0] };
+        let b = Tensor { shape: vec![3], data: vec![1.0, 10.0, 100.0] };
+        let recieved = tensordot(&a, &b, (&[], &[]));
+        assert_eq!(recieved, Tensor { shape: vec![2, 3], data: vec![1.0, 10.0, 100.0, 2.0, 20.0, 200.0] });
+    }
+
+    #[test]
+    #[should_panic(expected = "Incompatible shapes to tensordot: [2, 3] vs [2, 3] along axes [1] and [0]")]
+    fn axes_mismatch() {
+        let a = Tensor::<f64>::zeros(vec![2, 3]);
+        tensordot(&a, &a, (&[1], &[0]));
+    }
+}
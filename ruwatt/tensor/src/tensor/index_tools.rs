@@ -1,5 +1,5 @@
 use std::marker::PhantomData;
-use super::IndexError;
+use super::TensorError;
 
 pub struct IndexTools<T> {
     _marker: PhantomData<T>
@@ -45,7 +45,7 @@ impl<T> IndexTools<T> where T: Copy {
         shape[1]
     }
 
-    pub fn row(index: usize, shape: &Vec<usize>, data: &Vec<T>) -> Result<Vec<T>, IndexError> {
+    pub fn row(index: usize, shape: &Vec<usize>, data: &Vec<T>) -> Result<Vec<T>, TensorError> {
         let row_count = Self::row_count(shape);
         let col_count = Self::col_count(shape);
         if index < row_count {
@@ -53,11 +53,11 @@ impl<T> IndexTools<T> where T: Copy {
             let end = col_count * (index + 1);
             Ok(data[start..end].to_vec())        
         } else {
-            Err(IndexError::IndexOutOfBounds)
+            Err(TensorError::IndexOutOfRange { index, size: row_count })
         }
     }
 
-    pub fn col(index: usize, shape: &Vec<usize>, data: &Vec<T>) -> Result<Vec<T>, IndexError> {
+    pub fn col(index: usize, shape: &Vec<usize>, data: &Vec<T>) -> Result<Vec<T>, TensorError> {
         let row_count = Self::row_count(shape);
         let col_count = Self::col_count(shape);
         if index < col_count {
@@ -66,7 +66,7 @@ impl<T> IndexTools<T> where T: Copy {
                 .collect();
             Ok(result)             
         } else {
-            Err(IndexError::IndexOutOfBounds)
+            Err(TensorError::IndexOutOfRange { index, size: col_count })
         }
 
     }
@@ -2,10 +2,12 @@ pub mod tensor;
 pub mod constructors;
 pub mod display;
 pub mod split;
-pub mod index_error;
+pub mod tensor_error;
+pub mod reduce;
+pub mod shape;
 
 mod index_tools;
 
 pub use tensor::{ Tensor, TensorType, VectorType };
 pub use index_tools::IndexTools;
-pub use index_error::IndexError;
+pub use tensor_error::TensorError;
@@ -0,0 +1,128 @@
+use num::Float;
+use crate::Tensor;
+
+impl<T> Tensor<T> where T: Float {
+    pub fn sum_axis(&self, axis: usize, keepdims: bool) -> Self {
+        self.reduce_axis(axis, keepdims, |values| values.fold(T::zero(), |sum, value| sum + value))
+    }
+
+    pub fn prod_axis(&self, axis: usize, keepdims: bool) -> Self {
+        self.reduce_axis(axis, keepdims, |values| values.fold(T::one(), |prod, value| prod * value))
+    }
+
+    pub fn mean_axis(&self, axis: usize, keepdims: bool) -> Self {
+        let count = T::from(self.axis_len(axis)).unwrap();
+        self.reduce_axis(axis, keepdims, |values| values.fold(T::zero(), |sum, value| sum + value) / count)
+    }
+
+    pub fn max_axis(&self, axis: usize, keepdims: bool) -> Self {
+        self.assert_reducible(axis);
+        self.reduce_axis(axis, keepdims, |values| values.fold(T::neg_infinity(), T::max))
+    }
+
+    pub fn min_axis(&self, axis: usize, keepdims: bool) -> Self {
+        self.assert_reducible(axis);
+        self.reduce_axis(axis, keepdims, |values| values.fold(T::infinity(), T::min))
+    }
+
+    /// Index of the first largest value along `axis`
+    pub fn argmax_axis(&self, axis: usize, keepdims: bool) -> Self {
+        self.assert_reducible(axis);
+        self.reduce_axis(axis, keepdims, |values| Self::arg_best(values, |value, best| value > best))
+    }
+
+    /// Index of the first smallest value along `axis`
+    pub fn argmin_axis(&self, axis: usize, keepdims: bool) -> Self {
+        self.assert_reducible(axis);
+        self.reduce_axis(axis, keepdims, |values| Self::arg_best(values, |value, best| value < best))
+    }
+
+    fn arg_best(mut values: impl Iterator<Item = T>, better: impl Fn(T, T) -> bool) -> T {
+        let first = values.next().unwrap();
+        let (index, _) = values.enumerate()
+            .fold((0, first), |(index, best), (i, value)| if better(value, best) { (i + 1, value) } else { (index, best) });
+        T::from(index).unwrap()
+    }
+
+    fn axis_len(&self, axis: usize) -> usize {
+        assert!(axis < self.shape.len(), "Axis {} out of range for shape {:?}", axis, self.shape);
+        self.shape[axis]
+    }
+
+    fn assert_reducible(&self, axis: usize) {
+        assert!(self.axis_len(axis) > 0, "Can't reduce empty axis {} of shape {:?}", axis, self.shape);
+    }
+
+    /// Applies `f` to the values along `axis` for every position of the other axes
+    fn reduce_axis<F>(&self, axis: usize, keepdims: bool, f: F) -> Self where F: Fn(&mut dyn Iterator<Item = T>) -> T {
+        let len = self.axis_len(axis);
+        let outer: usize = self.shape[..axis].iter().product();
+        let inner: usize = self.shape[axis + 1..].iter().product();
+        let mut data = Vec::with_capacity(outer * inner);
+        for o in 0..outer {
+            for i in 0..inner {
+                let mut values = (0..len).map(|k| self.data[(o * len + k) * inner + i]);
+                data.push(f(&mut values));
+            }
+        }
+        let mut shape = self.shape.clone();
+        if keepdims {
+            shape[axis] = 1;
+        } else {
+            shape.remove(axis);
+        }
+        Tensor { shape, data }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Matrix, Tensor, Vector};
+
+    fn matrix() -> Tensor {
+        Matrix::new(vec![
+            vec![ 1.0, 5.0, 3.0 ],
+            vec![ 4.0, 2.0, 6.0 ]
+        ])
+    }
+
+    #[test]
+    fn sum_mean_prod() {
+        let matrix = matrix();
+        assert_eq!(matrix.sum_axis(0, true), Vector::bra(vec![5.0, 7.0, 9.0]));
+        assert_eq!(matrix.sum_axis(1, true), Vector::ket(vec![9.0, 12.0]));
+        assert_eq!(matrix.mean_axis(0, false), Tensor { shape: vec![3], data: vec![2.5, 3.5, 4.5] });
+        assert_eq!(matrix.prod_axis(1, false), Tensor { shape: vec![2], data: vec![15.0, 48.0] });
+    }
+
+    #[test]
+    fn max_min() {
+        let matrix = matrix();
+        assert_eq!(matrix.max_axis(0, true), Vector::bra(vec![4.0, 5.0, 6.0]));
+        assert_eq!(matrix.min_axis(1, true), Vector::ket(vec![1.0, 2.0]));
+    }
+
+    #[test]
+    fn argmax_argmin() {
+        let matrix = matrix();
+        assert_eq!(matrix.argmax_axis(1, false), Tensor { shape: vec![2], data: vec![1.0, 2.0] });
+        assert_eq!(matrix.argmin_axis(0, true), Vector::bra(vec![0.0, 1.0, 0.0]));
+        let ties = Vector::bra(vec![3.0, 1.0, 3.0]);
+        assert_eq!(ties.argmax_axis(1, false).data, vec![0.0]);
+    }
+
+    #[test]
+    fn rank3() {
+        let tensor = Tensor::range(0.0, vec![2, 3, 2]);
+        let recieved = tensor.sum_axis(1, false);
+        let expected = Tensor { shape: vec![2, 2], data: vec![6.0, 9.0, 24.0, 27.0] };
+        assert_eq!(recieved, expected);
+        assert_eq!(tensor.max_axis(2, true).shape, vec![2, 3, 1]);
+    }
+
+    #[test]
+    #[should_panic(expected = "Axis 2 out of range for shape [2, 3]")]
+    fn axis_out_of_range() {
+        matrix().sum_axis(2, false);
+    }
+}
@@ -0,0 +1,173 @@
+use num::Float;
+use crate::{Tensor, TensorError};
+
+impl<T> Tensor<T> where T: Float {
+    /// Same data with a new shape, a single `-1` dimension is inferred from the size
+    pub fn reshape(&self, shape: &[isize]) -> Self {
+        let size = self.data.len();
+        let inferred: Vec<usize> = (0..shape.len()).filter(|&axis| shape[axis] == -1).collect();
+        assert!(inferred.len() <= 1, "Only one dimension can be inferred: {:?}", shape);
+        assert!(shape.iter().all(|&dim| dim >= -1), "Invalid shape {:?}", shape);
+        let known: usize = shape.iter().filter(|&&dim| dim != -1).map(|&dim| dim as usize).product();
+        let mut new_shape: Vec<usize> = shape.iter().map(|&dim| dim.max(0) as usize).collect();
+        if let Some(&axis) = inferred.first() {
+            assert!(known > 0, "Can't reshape {:?} into {:?}", self.shape, shape);
+            new_shape[axis] = size / known;
+        }
+        assert_eq!(new_shape.iter().product::<usize>(), size, "Can't reshape {:?} into {:?}", self.shape, shape);
+        Self { shape: new_shape, data: self.data.clone() }
+    }
+
+    /// Reorders axes: axis `i` of the result is axis `axes[i]` of `self`
+    pub fn permute(&self, axes: &[usize]) -> Self {
+        self.view().permute(axes).to_tensor()
+    }
+
+    /// Removes every axis of size 1
+    pub fn squeeze(&self) -> Self {
+        let shape = self.shape.iter().cloned().filter(|&dim| dim != 1).collect();
+        Self { shape, data: self.data.clone() }
+    }
+
+    pub fn squeeze_axis(&self, axis: usize) -> Self {
+        assert!(
+            axis < self.shape.len() && self.shape[axis] == 1,
+            "Can't squeeze axis {} of shape {:?}", axis, self.shape
+        );
+        let mut shape = self.shape.clone();
+        shape.remove(axis);
+        Self { shape, data: self.data.clone() }
+    }
+
+    /// Inserts an axis of size 1 before `axis`
+    pub fn unsqueeze(&self, axis: usize) -> Self {
+        assert!(axis <= self.shape.len(), "Can't unsqueeze axis {} of shape {:?}", axis, self.shape);
+        let mut shape = self.shape.clone();
+        shape.insert(axis, 1);
+        Self { shape, data: self.data.clone() }
+    }
+
+    pub fn flatten(&self) -> Self {
+        Self { shape: vec![self.data.len()], data: self.data.clone() }
+    }
+
+    /// Joins tensors along an existing axis, all other dimensions have to match.
+    /// Empty tensors are skipped.
+    pub fn concat(tensors: &[Tensor<T>], axis: usize) -> Self {
+        Self::try_concat(tensors, axis).unwrap_or_else(|error| panic!("{error}"))
+    }
+
+    pub fn try_concat(tensors: &[Tensor<T>], axis: usize) -> Result<Self, TensorError> {
+        let tensors: Vec<&Tensor<T>> = tensors.iter().filter(|tensor| !tensor.is_empty()).collect();
+        let Some(first) = tensors.first() else {
+            return Ok(Self::empty());
+        };
+        if axis >= first.shape.len() {
+            return Err(TensorError::IndexOutOfRange { index: axis, size: first.shape.len() });
+        }
+        for tensor in tensors.iter() {
+            let compatible = tensor.shape.len() == first.shape.len() && (0..first.shape.len())
+                .all(|i| i == axis || tensor.shape[i] == first.shape[i]);
+            if !compatible {
+                return Err(TensorError::ShapeMismatch {
+                    operation: "concat",
+                    left: first.shape.clone(),
+                    right: tensor.shape.clone()
+                });
+            }
+        }
+
+        let outer: usize = first.shape[..axis].iter().product();
+        let inner: usize = first.shape[axis + 1..].iter().product();
+        let mut shape = first.shape.clone();
+        shape[axis] = tensors.iter().map(|tensor| tensor.shape[axis]).sum();
+        let mut data = Vec::with_capacity(shape.iter().product());
+        for o in 0..outer {
+            for tensor in tensors.iter() {
+                let chunk = tensor.shape[axis] * inner;
+                data.extend_from_slice(&tensor.data[o * chunk..(o + 1) * chunk]);
+            }
+        }
+        Ok(Self { shape, data })
+    }
+
+    /// Joins tensors of the same shape along a new axis
+    pub fn stack(tensors: &[Tensor<T>], axis: usize) -> Self {
+        let unsqueezed: Vec<Tensor<T>> = tensors.iter().map(|tensor| tensor.unsqueeze(axis)).collect();
+        if let Some(first) = tensors.first() {
+            tensors.iter().for_each(|tensor| assert_shape!(first, tensor));
+        }
+        Self::concat(&unsqueezed, axis)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Matrix, Tensor, Vector};
+
+    #[test]
+    fn reshape() {
+        let tensor = Tensor::range(0.0, vec![2, 6]);
+        assert_eq!(tensor.reshape(&[3, -1]).shape, vec![3, 4]);
+        assert_eq!(tensor.reshape(&[2, 3, 2]).data, tensor.data);
+        assert_eq!(tensor.reshape(&[-1]).shape, vec![12]);
+    }
+
+    #[test]
+    #[should_panic(expected = "Can't reshape [2, 6] into [5, -1]")]
+    fn reshape_incompatible() {
+        Tensor::<f64>::range(0.0, vec![2, 6]).reshape(&[5, -1]);
+    }
+
+    #[test]
+    #[should_panic(expected = "Only one dimension can be inferred: [-1, -1]")]
+    fn reshape_two_inferred() {
+        Tensor::<f64>::range(0.0, vec![2, 6]).reshape(&[-1, -1]);
+    }
+
+    #[test]
+    fn permute() {
+        let tensor = Tensor::range(0.0, vec![2, 3, 4]);
+        let permuted = tensor.permute(&[2, 0, 1]);
+        assert_eq!(permuted.shape, vec![4, 2, 3]);
+        assert_eq!(permuted.get(vec![3, 1, 2]), tensor.get(vec![1, 2, 3]));
+        let matrix = Matrix::new(vec![vec![1.0, 2.0, 3.0], vec![4.0, 5.0, 6.0]]);
+        assert_eq!(matrix.permute(&[1, 0]), matrix.tr());
+    }
+
+    #[test]
+    fn squeeze_unsqueeze_flatten() {
+        let ket = Vector::ket(vec![1.0, 2.0, 3.0]);
+        assert_eq!(ket.squeeze().shape, vec![3]);
+        assert_eq!(ket.squeeze_axis(1).unsqueeze(0), Vector::bra(vec![1.0, 2.0, 3.0]));
+        assert_eq!(Tensor::<f64>::zeros(vec![2, 1, 3]).flatten().shape, vec![6]);
+    }
+
+    #[test]
+    fn concat() {
+        let a = Tensor::range(0.0, vec![2, 1, 2]);
+        let b = Tensor::range(10.0, vec![2, 2, 2]);
+        let recieved = Tensor::concat(&[a, b], 1);
+        let expected = Tensor {
+            shape: vec![2, 3, 2],
+            data: vec![0.0, 1.0, 10.0, 11.0, 12.0, 13.0, 2.0, 3.0, 14.0, 15.0, 16.0, 17.0]
+        };
+        assert_eq!(recieved, expected);
+    }
+
+    #[test]
+    fn stack() {
+        let a = Vector::bra(vec![1.0, 2.0]);
+        let b = Vector::bra(vec![3.0, 4.0]);
+        let recieved = Tensor::stack(&[a.clone(), b.clone()], 0);
+        assert_eq!(recieved, Tensor { shape: vec![2, 1, 2], data: vec![1.0, 2.0, 3.0, 4.0] });
+        let recieved = Tensor::stack(&[a, b], 2);
+        assert_eq!(recieved, Tensor { shape: vec![1, 2, 2], data: vec![1.0, 3.0, 2.0, 4.0] });
+    }
+
+    #[test]
+    #[should_panic(expected = "Incompatible shapes to concat: [2, 2] vs [3, 3]")]
+    fn concat_incompatible() {
+        Tensor::concat(&[Tensor::<f64>::zeros(vec![2, 2]), Tensor::zeros(vec![3, 3])], 1);
+    }
+}
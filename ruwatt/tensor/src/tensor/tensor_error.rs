@@ -0,0 +1,29 @@
+use std::error::Error;
+use std::fmt;
+
+#[derive(Debug, PartialEq, Clone)]
+pub enum TensorError {
+    ShapeMismatch { operation: &'static str, left: Vec<usize>, right: Vec<usize> },
+    WrongRank { expected: usize, shape: Vec<usize> },
+    NotSquareMatrix { shape: Vec<usize> },
+    SingularMatrix,
+    NotPositiveDefinite,
+    IndexOutOfRange { index: usize, size: usize }
+}
+impl fmt::Display for TensorError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TensorError::ShapeMismatch { operation, left, right } => {
+                write!(f, "Incompatible shapes to {}: {:?} vs {:?}", operation, left, right)
+            },
+            TensorError::WrongRank { expected, shape } => {
+                write!(f, "Tensor of rank {} expected: shape = {:?}", expected, shape)
+            },
+            TensorError::NotSquareMatrix { shape } => write!(f, "Tensor is not a square matrix: shape = {:?}", shape),
+            TensorError::SingularMatrix => write!(f, "Matrix is singular or near singular"),
+            TensorError::NotPositiveDefinite => write!(f, "Matrix is not positive definite"),
+            TensorError::IndexOutOfRange { index, size } => write!(f, "Index {} out of range for size {}", index, size),
+        }
+    }
+}
+impl Error for TensorError {}
@@ -0,0 +1,4 @@
+pub mod tensor_view;
+pub mod view_operators;
+
+pub use tensor_view::{TensorView, TensorViewIterator};
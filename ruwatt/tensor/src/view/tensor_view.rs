@@ -0,0 +1,262 @@
+use num::Float;
+use std::ops::Range;
+use crate::Tensor;
+
+/// Borrowed strided window into the data of a `Tensor`.
+/// Element `indices` lives at `offset + Σ indices[i] * strides[i]`.
+#[derive(Debug, Clone)]
+pub struct TensorView<'a, T> where T: Float {
+    pub data: &'a [T],
+    pub shape: Vec<usize>,
+    pub strides: Vec<usize>,
+    pub offset: usize
+}
+
+pub(crate) fn contiguous_strides(shape: &[usize]) -> Vec<usize> {
+    let mut strides = vec![1; shape.len()];
+    for i in (0..shape.len().saturating_sub(1)).rev() {
+        strides[i] = strides[i + 1] * shape[i + 1];
+    }
+    strides
+}
+
+impl<'a, T> TensorView<'a, T> where T: Float {
+    pub fn new(tensor: &'a Tensor<T>) -> Self {
+        Self {
+            data: &tensor.data,
+            shape: tensor.shape.clone(),
+            strides: contiguous_strides(&tensor.shape),
+            offset: 0
+        }
+    }
+
+    pub fn size(&self) -> usize {
+        self.shape.iter().product()
+    }
+
+    pub fn is_matrix(&self) -> bool {
+        self.shape.len() == 2
+    }
+
+    pub fn row_count(&self) -> usize {
+        assert_matrix!(self);
+        self.shape[0]
+    }
+
+    pub fn col_count(&self) -> usize {
+        assert_matrix!(self);
+        self.shape[1]
+    }
+
+    pub fn is_contiguous(&self) -> bool {
+        self.strides == contiguous_strides(&self.shape)
+    }
+
+    pub fn get(&self, indices: &[usize]) -> T {
+        assert!(
+            indices.len() == self.shape.len() && indices.iter().zip(self.shape.iter()).all(|(i, dim)| i < dim),
+            "Index out of range: shape = {:?}, indices = {:?}",
+            self.shape,
+            indices
+        );
+        let index = indices.iter().zip(self.strides.iter()).fold(self.offset, |index, (i, stride)| index + i * stride);
+        self.data[index]
+    }
+
+    /// Narrows every axis to the given range, one range per axis
+    pub fn slice(&self, ranges: &[Range<usize>]) -> Self {
+        assert_eq!(ranges.len(), self.shape.len(), "One range per axis expected: shape = {:?}", self.shape);
+        let mut offset = self.offset;
+        let mut shape = Vec::with_capacity(ranges.len());
+        for (axis, range) in ranges.iter().enumerate() {
+            assert!(
+                range.start <= range.end && range.end <= self.shape[axis],
+                "Slice {:?} out of range for axis {} of shape {:?}",
+                range,
+                axis,
+                self.shape
+            );
+            offset += range.start * self.strides[axis];
+            shape.push(range.end - range.start);
+        }
+        Self { data: self.data, shape, strides: self.strides.clone(), offset }
+    }
+
+    /// Swaps the axes of a matrix without copying
+    pub fn tr(&self) -> Self {
+        assert_matrix!(self);
+        Self {
+            data: self.data,
+            shape: vec![self.shape[1], self.shape[0]],
+            strides: vec![self.strides[1], self.strides[0]],
+            offset: self.offset
+        }
+    }
+
+    /// Reorders axes without copying: axis `i` of the result is axis `axes[i]` of `self`
+    pub fn permute(&self, axes: &[usize]) -> Self {
+        let mut sorted = axes.to_vec();
+        sorted.sort();
+        assert!(
+            sorted == (0..self.shape.len()).collect::<Vec<_>>(),
+            "Axes {:?} are not a permutation for shape {:?}", axes, self.shape
+        );
+        Self {
+            data: self.data,
+            shape: axes.iter().map(|&axis| self.shape[axis]).collect(),
+            strides: axes.iter().map(|&axis| self.strides[axis]).collect(),
+            offset: self.offset
+        }
+    }
+
+    pub fn row(&self, index: usize) -> Self {
+        let cols = self.col_count();
+        self.slice(&[index..index + 1, 0..cols])
+    }
+
+    pub fn col(&self, index: usize) -> Self {
+        let rows = self.row_count();
+        self.slice(&[0..rows, index..index + 1])
+    }
+
+    /// Stretches axes of size 1 to `shape` (NumPy rules) with zero strides
+    pub fn broadcast_to(&self, shape: &[usize]) -> Option<Self> {
+        if shape.len() < self.shape.len() {
+            return None;
+        }
+        let shift = shape.len() - self.shape.len();
+        let mut strides = vec![0; shape.len()];
+        for (axis, &dim) in self.shape.iter().enumerate() {
+            if dim == shape[axis + shift] {
+                strides[axis + shift] = self.strides[axis];
+            } else if dim != 1 {
+                return None;
+            }
+        }
+        Some(Self { data: self.data, shape: shape.to_vec(), strides, offset: self.offset })
+    }
+
+    /// Values in row-major order of the view
+    pub fn iter(&self) -> TensorViewIterator<'_, 'a, T> {
+        TensorViewIterator {
+            view: self,
+            indices: vec![0; self.shape.len()],
+            index: self.offset,
+            remaining: self.size()
+        }
+    }
+
+    pub fn to_tensor(&self) -> Tensor<T> {
+        let data = if self.is_contiguous() {
+            self.data[self.offset..self.offset + self.size()].to_vec()
+        } else {
+            self.iter().collect()
+        };
+        Tensor { shape: self.shape.clone(), data }
+    }
+}
+
+pub struct TensorViewIterator<'v, 'a, T> where T: Float {
+    view: &'v TensorView<'a, T>,
+    indices: Vec<usize>,
+    index: usize,
+    remaining: usize
+}
+
+impl<'v, 'a, T> Iterator for TensorViewIterator<'v, 'a, T> where T: Float {
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.remaining == 0 {
+            return None;
+        }
+        let value = self.view.data[self.index];
+        self.remaining -= 1;
+        let shape = &self.view.shape;
+        let strides = &self.view.strides;
+        for axis in (0..shape.len()).rev() {
+            self.indices[axis] += 1;
+            self.index += strides[axis];
+            if self.indices[axis] < shape[axis] {
+                break;
+            }
+            self.index -= strides[axis] * shape[axis];
+            self.indices[axis] = 0;
+        }
+        Some(value)
+    }
+}
+
+impl<T> Tensor<T> where T: Float {
+    pub fn view(&self) -> TensorView<'_, T> {
+        TensorView::new(self)
+    }
+
+    pub fn row_view(&self, index: usize) -> TensorView<'_, T> {
+        self.view().row(index)
+    }
+
+    pub fn col_view(&self, index: usize) -> TensorView<'_, T> {
+        self.view().col(index)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Matrix, Tensor, Vector};
+
+    fn matrix123() -> Tensor {
+        Matrix::square((1..=9).map(|x| x as f64).collect())
+    }
+
+    #[test]
+    fn view() {
+        let matrix = matrix123();
+        let view = matrix.view();
+        assert_eq!(view.strides, vec![3, 1]);
+        assert!(view.is_contiguous());
+        assert_eq!(view.get(&[1, 2]), 6.0);
+        assert_eq!(view.to_tensor(), matrix);
+    }
+
+    #[test]
+    fn slice() {
+        let matrix = matrix123();
+        let view = matrix.view().slice(&[1..3, 0..2]);
+        assert_eq!(view.shape, vec![2, 2]);
+        assert!(!view.is_contiguous());
+        assert_eq!(view.to_tensor(), Matrix::new(vec![vec![4.0, 5.0], vec![7.0, 8.0]]));
+    }
+
+    #[test]
+    fn tr() {
+        let matrix = matrix123();
+        let view = matrix.view().tr();
+        assert_eq!(view.get(&[0, 1]), 4.0);
+        assert_eq!(view.to_tensor(), matrix.tr());
+    }
+
+    #[test]
+    fn row_col() {
+        let matrix = matrix123();
+        assert_eq!(matrix.row_view(1).to_tensor(), Vector::bra(vec![4.0, 5.0, 6.0]));
+        assert_eq!(matrix.col_view(1).to_tensor(), Vector::ket(vec![2.0, 5.0, 8.0]));
+        assert_eq!(matrix.col_view(2).row(1).get(&[0, 0]), 6.0);
+    }
+
+    #[test]
+    fn broadcast_to() {
+        let vector = Vector::bra(vec![1.0, 2.0]);
+        let view = vector.view().broadcast_to(&[2, 2, 2]).unwrap();
+        assert_eq!(view.strides, vec![0, 0, 1]);
+        assert_eq!(view.iter().collect::<Vec<_>>(), vec![1.0, 2.0, 1.0, 2.0, 1.0, 2.0, 1.0, 2.0]);
+        assert!(vector.view().broadcast_to(&[2, 3]).is_none());
+    }
+
+    #[test]
+    #[should_panic(expected = "Slice 1..4 out of range for axis 0 of shape [3, 3]")]
+    fn slice_out_of_range() {
+        let matrix = matrix123();
+        matrix.view().slice(&[1..4, 0..1]);
+    }
+}
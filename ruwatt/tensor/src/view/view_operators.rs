@@ -0,0 +1,70 @@
+use std::ops;
+use num::Float;
+use crate::{arithmetic_views, Tensor, TensorView};
+
+macro_rules! impl_view_operator {
+    ($trait:ident, $method:ident, $op:tt) => {
+        impl<'a, 'b, T> ops::$trait<&TensorView<'b, T>> for &TensorView<'a, T> where T: Float {
+            type Output = Tensor<T>;
+
+            fn $method(self, other: &TensorView<'b, T>) -> Tensor<T> {
+                arithmetic_views(self, other, &|&x, &y| x $op y)
+            }
+        }
+
+        impl<'b, T> ops::$trait<&TensorView<'b, T>> for &Tensor<T> where T: Float {
+            type Output = Tensor<T>;
+
+            fn $method(self, other: &TensorView<'b, T>) -> Tensor<T> {
+                arithmetic_views(&self.view(), other, &|&x, &y| x $op y)
+            }
+        }
+
+        impl<'a, T> ops::$trait<&Tensor<T>> for &TensorView<'a, T> where T: Float {
+            type Output = Tensor<T>;
+
+            fn $method(self, other: &Tensor<T>) -> Tensor<T> {
+                arithmetic_views(self, &other.view(), &|&x, &y| x $op y)
+            }
+        }
+
+        impl<'a, T> ops::$trait<T> for &TensorView<'a, T> where T: Float {
+            type Output = Tensor<T>;
+
+            fn $method(self, other: T) -> Tensor<T> {
+                Tensor { shape: self.shape.clone(), data: self.iter().map(|x| x $op other).collect() }
+            }
+        }
+    };
+}
+
+impl_view_operator!(Add, add, +);
+impl_view_operator!(Sub, sub, -);
+impl_view_operator!(Mul, mul, *);
+impl_view_operator!(Div, div, /);
+
+#[cfg(test)]
+mod tests {
+    use crate::{Matrix, Vector};
+
+    #[test]
+    fn view_view() {
+        let matrix = Matrix::square(vec![1.0, 2.0, 3.0, 4.0]);
+        let recieved = &matrix.view() + &matrix.view().tr();
+        assert_eq!(recieved, Matrix::square(vec![2.0, 5.0, 5.0, 8.0]));
+    }
+
+    #[test]
+    fn tensor_view_broadcast() {
+        let matrix = Matrix::square(vec![1.0, 2.0, 3.0, 4.0]);
+        let bias = Vector::bra(vec![10.0, 20.0]);
+        assert_eq!(&matrix.col_view(1) - &bias, Matrix::square(vec![-8.0, -18.0, -6.0, -16.0]));
+        assert_eq!(&bias * &matrix.row_view(1), Vector::bra(vec![30.0, 80.0]));
+    }
+
+    #[test]
+    fn view_number() {
+        let matrix = Matrix::square(vec![1.0, 2.0, 3.0, 4.0]);
+        assert_eq!(&matrix.col_view(0) / 2.0, Vector::ket(vec![0.5, 1.5]));
+    }
+}